@@ -1,11 +1,7 @@
 fn main() {
-    if let Ok(it) = dotenvy::dotenv_iter() {
-        for item in it {
-            if let Ok((key, value)) = item {
-                if key == "FIREBASE_URL" {
-                    println!("cargo:rustc-env={}={}", key, value);
-                }
-            }
+    for (key, value) in dotenvy::dotenv_iter().into_iter().flatten().flatten() {
+        if key == "FIREBASE_URL" {
+            println!("cargo:rustc-env={}={}", key, value);
         }
     }
     
@@ -3,6 +3,48 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct AlienFactory;
 
+/// Hit points of a factory. It is destroyed when they run out.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Health {
+    pub current: f32,
+    pub max: f32,
+    /// Seconds left before it starts regenerating after a hit.
+    pub regen_cooldown: f32,
+}
+
+/// The damage stage a factory is currently drawn at, so its material is only swapped
+/// when the stage changes rather than on every bit of regeneration.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DamageStage(pub usize);
+
+impl Health {
+    pub fn full(max: f32) -> Self {
+        Self { current: max, max, regen_cooldown: 0.0 }
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.current < self.max
+    }
+
+    /// Damage stage out of `stages`, from 0 at full health to `stages - 1` when nearly destroyed.
+    pub fn stage(&self, stages: usize) -> usize {
+        let lost = 1.0 - (self.current / self.max).clamp(0.0, 1.0);
+        ((lost * stages as f32) as usize).min(stages.saturating_sub(1))
+    }
+}
+
+/// Sent when a factory is destroyed, where it stood.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct FactoryDestroyed {
+    pub position: Vec3,
+}
+
+/// Expanding ring left by a destroyed factory, which cleans the pollution it passes over.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Shockwave {
+    pub age: f32,
+}
+
 #[derive(Component)]
 pub struct FactorySpawner {
     pub timer: Timer,
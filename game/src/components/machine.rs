@@ -1,6 +1,84 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct AlienMachine {
     pub velocity: Vec3,
+    /// Seconds left before the machine can move again.
+    pub stun: f32,
+    /// Seconds until the kind's special action can be used again.
+    pub cooldown: f32,
+}
+
+impl AlienMachine {
+    pub fn new(velocity: Vec3) -> Self {
+        Self { velocity, stun: 0.0, cooldown: 0.0 }
+    }
+}
+
+/// Sent when the player runs into a kamikaze without dashing, so `kamikaze_system` sets
+/// it off as if it had reached its trigger range.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct KamikazeContact {
+    pub machine: Entity,
+}
+
+/// What a machine is doing, decided each frame by `alien_ai_system`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BrainState {
+    /// Waits near its factory before picking a new patrol waypoint.
+    #[default]
+    Idle,
+    /// Heads for a waypoint around its factory.
+    Patrol,
+    /// Steers toward the player.
+    Chase,
+    /// Runs from a dashing player.
+    Flee,
+    /// Heads back to its factory after losing the player.
+    Return,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EnemyBrain {
+    pub state: BrainState,
+    /// Where the machine patrols and returns to. Its factory, or for a repair drone the
+    /// factory it is looking after.
+    pub home: Vec3,
+    pub waypoint: Vec3,
+    /// Seconds spent in the current state.
+    pub timer: f32,
+}
+
+impl EnemyBrain {
+    pub fn new(home: Vec3) -> Self {
+        Self { state: BrainState::Idle, home, waypoint: home, timer: 0.0 }
+    }
+
+    pub fn enter(&mut self, state: BrainState) {
+        if self.state != state {
+            self.state = state;
+            self.timer = 0.0;
+        }
+    }
+}
+
+/// What a machine is, which picks its stats from `EnemySettings::archetypes` and its behaviour.
+#[derive(Component, Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MachineKind {
+    /// Rams the player.
+    #[default]
+    Chaser,
+    /// Slow, leaves a trail of polluted tiles.
+    Polluter,
+    /// Armoured: a dash only stuns it.
+    Tank,
+    /// Charges the player and blows up into a burst of pollution.
+    Kamikaze,
+    /// Stays with the factories and heals them.
+    Repair,
+}
+
+impl MachineKind {
+    pub const ALL: [Self; 5] = [Self::Chaser, Self::Polluter, Self::Tank, Self::Kamikaze, Self::Repair];
 }
\ No newline at end of file
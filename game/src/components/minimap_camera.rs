@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Render layer seen only by the minimap cameras, holding the markers. The planet
+/// chunks are on it as well as on the default layer.
+pub const MINIMAP_LAYER: usize = 1;
+
+/// Orthographic camera filming the planet for the minimap. Child of the `PlanetPivot`,
+/// so the map turns with it and the player always sits in the middle of the near side.
+#[derive(Component)]
+pub struct MinimapCamera {
+    /// Looks at the hemisphere opposite the player instead of the one under them.
+    pub far_side: bool,
+}
+
+/// Dot drawn over an entity on the minimap.
+#[derive(Component)]
+pub struct MinimapMarker;
\ No newline at end of file
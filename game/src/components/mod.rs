@@ -9,4 +9,5 @@ pub(super) mod machine;
 pub(super) mod dash;
 pub(super) mod ui;
 pub(super) mod orbs;
-pub(super) mod notification_timer;
\ No newline at end of file
+pub(super) mod notification_timer;
+pub(super) mod minimap_camera;
\ No newline at end of file
@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::spatial::SphereIndex;
 
 #[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
 pub enum TileState {
@@ -8,12 +12,328 @@ pub enum TileState {
     Polluted,
 }
 
-#[derive(Component, Reflect)]
+impl TileState {
+    /// Atlas UVs for the three corners of a triangle showing this state.
+    pub fn uvs(self) -> [[f32; 2]; 3] {
+        match self {
+            Self::Wasteland => [[0.0, 0.0], [0.5, 0.0], [0.25, 0.5]],
+            Self::Healthy => [[0.5, 0.0], [1.0, 0.0], [0.75, 0.5]],
+            Self::Polluted => [[0.0, 0.5], [0.5, 0.5], [0.25, 1.0]],
+        }
+    }
+
+    /// Centre and radius of the largest circle inside this state's atlas triangle.
+    pub fn incircle(self) -> (Vec2, f32) {
+        let [a, b, c] = self.uvs().map(Vec2::from);
+        let (side_a, side_b, side_c) = (b.distance(c), c.distance(a), a.distance(b));
+        let perimeter = side_a + side_b + side_c;
+        let center = (a * side_a + b * side_b + c * side_c) / perimeter;
+        let area = (b - a).perp_dot(c - a).abs() * 0.5;
+        (center, 2.0 * area / perimeter)
+    }
+}
+
+/// One paintable cell of the planet surface.
+#[derive(Debug, Clone, Reflect)]
+pub struct Tile {
+    /// Centre of the tile on the unit sphere.
+    pub centroid: Vec3,
+    /// Tiles sharing an edge or a corner with this one, in ascending order.
+    pub neighbors: Vec<usize>,
+    pub state: TileState,
+    /// The [PlanetChunk] whose mesh draws this tile.
+    pub chunk: usize,
+    /// The vertices drawing this tile in its chunk's mesh.
+    pub vertices: Range<usize>,
+}
+
+/// The planet surface as tiles, the single source of truth for tile state.
+///
+/// The mesh only mirrors it. Systems change tiles through
+/// [TileWriter](crate::plugins::game::TileWriter), which reports each change as a
+/// `TileStateChanged` message. The chunk meshes are repainted from those messages in `PostUpdate`.
+#[derive(Component, Reflect, Default)]
 #[reflect(Component)]
-pub struct PlanetData {
-    pub vertex_states: Vec<TileState>,
-    pub adjacency: Vec<Vec<usize>>, 
+pub struct PlanetTiles {
+    pub tiles: Vec<Tile>,
+    /// Tile centroids, for radius queries.
+    #[reflect(ignore)]
+    pub index: SphereIndex,
+    pub chunk_count: usize,
+}
+
+impl PlanetTiles {
+    /// One tile per triangle of a mesh with duplicated vertices (three per triangle).
+    /// Tiles sharing an edge or a corner are neighbours.
+    pub fn from_triangles(positions: &[[f32; 3]], chunk_centers: &[Vec3]) -> Self {
+        // Duplicated corners are matched by position, rounded to absorb float noise
+        let key = |p: [f32; 3]| p.map(|c| (c * 1000.0).round() as i32);
+
+        let tile_count = positions.len() / 3;
+        let mut tiles_at_corner: HashMap<[i32; 3], Vec<usize>> = HashMap::new();
+        for (vertex, pos) in positions.iter().enumerate().take(tile_count * 3) {
+            tiles_at_corner.entry(key(*pos)).or_default().push(vertex / 3);
+        }
+
+        let neighbors = (0..tile_count).map(|tile| {
+            let mut neighbors: Vec<usize> = positions[tile * 3..tile * 3 + 3].iter()
+                .flat_map(|pos| tiles_at_corner[&key(*pos)].iter().copied())
+                .filter(|&other| other != tile)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        }).collect();
+
+        Self::from_polygons(positions, &vec![3; tile_count], neighbors, chunk_centers)
+    }
+
+    /// Tiles drawn as triangle fans, like a [Goldberg](crate::goldberg::Goldberg) planet.
+    /// `positions` holds every tile's vertices, tile after tile, `vertex_counts[i]` of
+    /// them for tile `i`. All tiles start as [TileState::Wasteland].
+    ///
+    /// Each tile goes to the chunk whose centre in `chunk_centers` is closest to it.
+    pub fn from_polygons(
+        positions: &[[f32; 3]],
+        vertex_counts: &[usize],
+        neighbors: Vec<Vec<usize>>,
+        chunk_centers: &[Vec3],
+    ) -> Self {
+        let chunk_count = chunk_centers.len().max(1);
+        let mut chunk_sizes = vec![0; chunk_count];
+        let mut first_vertex = 0;
+
+        let tiles: Vec<Tile> = vertex_counts.iter().zip(neighbors).map(|(&count, neighbors)| {
+            let corners = &positions[first_vertex..first_vertex + count];
+            first_vertex += count;
+
+            let centroid = corners.iter().map(|pos| Vec3::from(*pos)).sum::<Vec3>().normalize();
+            let chunk = chunk_centers.iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.dot(centroid).total_cmp(&b.dot(centroid)))
+                .map_or(0, |(chunk, _)| chunk);
+
+            let start = chunk_sizes[chunk];
+            chunk_sizes[chunk] += count;
+
+            Tile {
+                centroid,
+                neighbors,
+                state: TileState::Wasteland,
+                chunk,
+                vertices: start..start + count,
+            }
+        }).collect();
+
+        let centroids: Vec<Vec3> = tiles.iter().map(|tile| tile.centroid).collect();
+        Self { index: SphereIndex::build(&centroids), tiles, chunk_count }
+    }
+
+    /// Builds one flat-shaded mesh per chunk, painted with the current tile states.
+    /// `positions` are the ones the tiles were built from.
+    pub fn chunk_meshes(&self, positions: &[[f32; 3]]) -> Vec<Mesh> {
+        let mut chunk_positions = vec![Vec::new(); self.chunk_count];
+        let mut first_vertex = 0;
+        for tile in &self.tiles {
+            let count = tile.vertices.len();
+            chunk_positions[tile.chunk].extend_from_slice(&positions[first_vertex..first_vertex + count]);
+            first_vertex += count;
+        }
+
+        let mut chunk_uvs: Vec<Vec<[f32; 2]>> = chunk_positions.iter().map(|p| vec![[0.0, 0.0]; p.len()]).collect();
+        for tile in &self.tiles {
+            Self::paint(tile, &mut chunk_uvs[tile.chunk]);
+        }
+
+        chunk_positions.into_iter().zip(chunk_uvs).map(|(positions, uvs)| {
+            let mut mesh = Mesh::new(
+                bevy::mesh::PrimitiveTopology::TriangleList,
+                bevy::asset::RenderAssetUsages::default(),
+            )
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+            mesh.compute_flat_normals();
+            mesh
+        }).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn state(&self, tile: usize) -> TileState {
+        self.tiles[tile].state
+    }
+
+    /// Tiles whose centre lies within `radius` of `center`, in unit-sphere units, ascending.
+    pub fn within(&self, center: Vec3, radius: f32) -> Vec<usize> {
+        self.index.within(center, radius)
+    }
+
+    /// Tile whose centre is closest to `dir`, a point on the unit sphere.
+    pub fn nearest(&self, dir: Vec3) -> Option<usize> {
+        self.index.nearest(dir)
+    }
+
+    /// Tiles currently in `state`, ascending.
+    pub fn with_state(&self, state: TileState) -> impl Iterator<Item = usize> + '_ {
+        self.tiles.iter().enumerate().filter(move |(_, tile)| tile.state == state).map(|(idx, _)| idx)
+    }
+
+    /// Changes a tile's state and returns the previous one. Doesn't touch the mesh.
+    ///
+    /// Low level: systems should go through `TileWriter` so the change is reported.
+    pub fn set_state(&mut self, tile: usize, state: TileState) -> TileState {
+        std::mem::replace(&mut self.tiles[tile].state, state)
+    }
+
+    /// Groups `tiles` by the chunk drawing them, one list per chunk. Chunks with an
+    /// empty list don't need repainting.
+    pub fn by_chunk(&self, tiles: impl IntoIterator<Item = usize>) -> Vec<Vec<usize>> {
+        let mut chunks = vec![Vec::new(); self.chunk_count];
+        for tile in tiles {
+            chunks[self.tiles[tile].chunk].push(tile);
+        }
+        chunks
+    }
+
+    /// Paints one tile's current state into its chunk's `uvs`.
+    pub fn paint_tile(&self, tile: usize, uvs: &mut [[f32; 2]]) {
+        Self::paint(&self.tiles[tile], uvs);
+    }
+
+    fn paint(tile: &Tile, uvs: &mut [[f32; 2]]) {
+        let uvs = &mut uvs[tile.vertices.clone()];
+
+        // A triangle fills the state's atlas triangle
+        if uvs.len() == 3 {
+            uvs.copy_from_slice(&tile.state.uvs());
+            return;
+        }
+
+        // A fan (centre, corner, next corner) is laid out as a regular polygon in the
+        // atlas triangle's incircle
+        let (center, radius) = tile.state.incircle();
+        let sides = uvs.len() / 3;
+        let corner = |idx: usize| {
+            let angle = std::f32::consts::TAU * (idx % sides) as f32 / sides as f32;
+            (center + Vec2::from_angle(angle) * radius).to_array()
+        };
+        for (side, fan) in uvs.chunks_exact_mut(3).enumerate() {
+            fan.copy_from_slice(&[center.to_array(), corner(side), corner(side + 1)]);
+        }
+    }
+}
+
+/// A chunk mesh's UV attribute, which [PlanetTiles] paints into.
+pub fn planet_uvs(mesh: &mut Mesh) -> Option<&mut [[f32; 2]]> {
+    match mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
+        Some(bevy::mesh::VertexAttributeValues::Float32x2(uvs)) => Some(uvs.as_mut_slice()),
+        _ => None,
+    }
 }
 
 #[derive(Component)]
-pub struct Planet;
\ No newline at end of file
+pub struct Planet;
+
+/// Child of the [Planet] drawing the tiles whose [Tile::chunk] matches.
+///
+/// Splitting the surface keeps a tile change from re-uploading the whole planet.
+#[derive(Component)]
+pub struct PlanetChunk(pub usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Corners of `Sphere::mesh().ico(subdivisions)`, three per triangle.
+    fn ico_positions(subdivisions: u32) -> Vec<[f32; 3]> {
+        let mut mesh = Sphere::new(1.0).mesh().ico(subdivisions).unwrap();
+        mesh.duplicate_vertices();
+        match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(bevy::mesh::VertexAttributeValues::Float32x3(positions)) => positions.clone(),
+            _ => panic!("ico-sphere without positions"),
+        }
+    }
+
+    /// Directions to the icosahedron's face centres, as the game splits the planet into chunks.
+    fn face_centers() -> Vec<Vec3> {
+        ico_positions(0).chunks_exact(3)
+            .map(|face| face.iter().map(|pos| Vec3::from(*pos)).sum::<Vec3>().normalize())
+            .collect()
+    }
+
+    fn shared_corners(positions: &[[f32; 3]], a: usize, b: usize) -> usize {
+        let corners = |tile: usize| &positions[tile * 3..tile * 3 + 3];
+        corners(a).iter().filter(|corner| corners(b).contains(corner)).count()
+    }
+
+    #[test]
+    fn icosahedron_faces_touch_three_by_edge_and_six_by_corner() {
+        let positions = ico_positions(0);
+        let tiles = PlanetTiles::from_triangles(&positions, &[]);
+        assert_eq!(tiles.len(), 20);
+
+        for (idx, tile) in tiles.tiles.iter().enumerate() {
+            assert_eq!(tile.neighbors.len(), 9);
+            assert!(tile.neighbors.windows(2).all(|pair| pair[0] < pair[1]));
+            for other in (0..tiles.len()).filter(|&other| other != idx) {
+                let shared = shared_corners(&positions, idx, other);
+                assert_eq!(tile.neighbors.contains(&other), shared > 0);
+                assert_eq!(tiles.tiles[other].neighbors.contains(&idx), shared > 0);
+            }
+            let by_edge = tile.neighbors.iter().filter(|&&other| shared_corners(&positions, idx, other) == 2).count();
+            assert_eq!(by_edge, 3);
+        }
+    }
+
+    #[test]
+    fn painting_a_tile_only_touches_its_vertices() {
+        let mut tiles = PlanetTiles::from_triangles(&ico_positions(0), &[]);
+        let mut uvs = vec![[0.0, 0.0]; tiles.len() * 3];
+        for tile in 0..tiles.len() {
+            tiles.paint_tile(tile, &mut uvs);
+        }
+        let before = uvs.clone();
+
+        assert_eq!(tiles.set_state(7, TileState::Polluted), TileState::Wasteland);
+        tiles.paint_tile(7, &mut uvs);
+
+        let vertices = tiles.tiles[7].vertices.clone();
+        assert_eq!(uvs[vertices.clone()], TileState::Polluted.uvs());
+        for vertex in (0..uvs.len()).filter(|vertex| !vertices.contains(vertex)) {
+            assert_eq!(uvs[vertex], before[vertex]);
+        }
+    }
+
+    #[test]
+    fn each_icosahedron_face_gets_a_chunk_of_its_own_tiles() {
+        let positions = ico_positions(1);
+        let tiles = PlanetTiles::from_triangles(&positions, &face_centers());
+        assert_eq!(tiles.chunk_count, 20);
+
+        let meshes = tiles.chunk_meshes(&positions);
+        assert_eq!(meshes.len(), 20);
+        for (chunk, mesh) in meshes.iter().enumerate() {
+            let drawn = tiles.tiles.iter().filter(|tile| tile.chunk == chunk).count();
+            assert_eq!(drawn, 4);
+            assert_eq!(mesh.count_vertices(), drawn * 3);
+        }
+    }
+
+    #[test]
+    fn changing_one_tile_dirties_only_its_chunk() {
+        let tiles = PlanetTiles::from_triangles(&ico_positions(1), &face_centers());
+        let chunk = tiles.tiles[13].chunk;
+
+        let dirty = tiles.by_chunk([13]);
+        assert_eq!(dirty.len(), 20);
+        for (idx, changed) in dirty.iter().enumerate() {
+            assert_eq!(changed.as_slice(), if idx == chunk { &[13][..] } else { &[] });
+        }
+    }
+}
\ No newline at end of file
@@ -12,6 +12,18 @@ pub struct DeathMenuRoot;
 #[derive(Component)]
 pub struct RestartButton;
 
+#[derive(Component)]
+pub struct ReplaySeedButton;
+
+#[derive(Component)]
+pub struct PauseMenuRoot;
+
+#[derive(Component)]
+pub struct ResumeButton;
+
+#[derive(Component)]
+pub struct PauseButton;
+
 #[derive(Component)]
 pub struct ExitButton;
 
@@ -61,4 +73,56 @@ pub struct MainMenuRoot;
 pub struct StartButton;
 
 #[derive(Component)]
-pub struct LeaderboardContentArea;
\ No newline at end of file
+pub struct LeaderboardContentArea;
+
+#[derive(Component)]
+pub struct SubmissionStatusText {
+    pub id: u64,
+}
+
+#[derive(Component)]
+pub struct ScoreQueueList;
+
+#[derive(Component)]
+pub struct RetryLeaderboardButton;
+
+#[derive(Component)]
+pub struct LeaderboardSpinner;
+
+#[derive(Component)]
+pub struct ShowMyRunsButton;
+
+#[derive(Component)]
+pub struct MyRunsPanel;
+
+#[derive(Component)]
+pub struct CloseMyRunsButton;
+
+#[derive(Component)]
+pub struct ContinueButton;
+
+/// One line of the planet status widget, showing the share of tiles in this state.
+#[derive(Component)]
+pub struct TileShareText(pub crate::components::planet::TileState);
+
+#[derive(Component)]
+pub struct FactoryCountText;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IndicatorTarget {
+    Factory,
+    Orb,
+}
+
+/// Screen-edge arrow pointing at the `slot`-th nearest off-screen target of its kind.
+#[derive(Component)]
+pub struct OffscreenIndicator {
+    pub target: IndicatorTarget,
+    pub slot: usize,
+}
+
+#[derive(Component)]
+pub struct IndicatorArrow;
+
+#[derive(Component)]
+pub struct IndicatorLabel;
\ No newline at end of file
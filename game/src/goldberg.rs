@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy::mesh::IcosphereError;
+use std::collections::HashMap;
+
+/// Geometry of a Goldberg polyhedron on the unit sphere: hexagons plus 12 pentagons.
+///
+/// It is the dual of an ico-sphere: every ico-sphere vertex becomes a tile whose
+/// corners are the centres of the triangles around it.
+pub struct Goldberg {
+    /// Triangle fans (centre, corner, next corner), tile after tile.
+    pub positions: Vec<[f32; 3]>,
+    /// How many of `positions` belong to each tile.
+    pub vertex_counts: Vec<usize>,
+    /// Tiles sharing an edge with each tile, in ascending order.
+    pub neighbors: Vec<Vec<usize>>,
+}
+
+impl Goldberg {
+    /// Builds the dual of `Sphere::mesh().ico(subdivisions)`, so both tilings get
+    /// finer at the same rate. Fails where that ico-sphere can't be built.
+    pub fn new(subdivisions: u32) -> Result<Self, IcosphereError> {
+        let ico = Sphere::new(1.0).mesh().ico(subdivisions)?;
+        let Some(bevy::mesh::VertexAttributeValues::Float32x3(raw_positions)) = ico.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            return Ok(Self { positions: Vec::new(), vertex_counts: Vec::new(), neighbors: Vec::new() });
+        };
+        let raw_indices: Vec<usize> = ico.indices().map(|indices| indices.iter().collect()).unwrap_or_default();
+
+        // Weld vertices the mesh may have split, rounded to absorb float noise
+        let key = |p: [f32; 3]| p.map(|c| (c * 10000.0).round() as i32);
+        let mut welded: HashMap<[i32; 3], usize> = HashMap::new();
+        let mut centers: Vec<Vec3> = Vec::new();
+        let remap: Vec<usize> = raw_positions.iter().map(|pos| {
+            *welded.entry(key(*pos)).or_insert_with(|| {
+                centers.push(Vec3::from(*pos).normalize());
+                centers.len() - 1
+            })
+        }).collect();
+
+        let faces: Vec<[usize; 3]> = raw_indices.chunks_exact(3)
+            .map(|face| [remap[face[0]], remap[face[1]], remap[face[2]]])
+            .collect();
+
+        let mut faces_at: Vec<Vec<usize>> = vec![Vec::new(); centers.len()];
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); centers.len()];
+        for (idx, face) in faces.iter().enumerate() {
+            for (corner, &vertex) in face.iter().enumerate() {
+                faces_at[vertex].push(idx);
+                neighbors[vertex].push(face[(corner + 1) % 3]);
+                neighbors[vertex].push(face[(corner + 2) % 3]);
+            }
+        }
+        for list in neighbors.iter_mut() {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        let face_centers: Vec<Vec3> = faces.iter()
+            .map(|face| face.iter().map(|&vertex| centers[vertex]).sum::<Vec3>().normalize())
+            .collect();
+
+        let mut positions = Vec::new();
+        let mut vertex_counts = Vec::with_capacity(centers.len());
+        for (center, around) in centers.iter().zip(&faces_at) {
+            // Corners in counter-clockwise order seen from outside, so the fan faces out
+            let (tangent, bitangent) = center.any_orthonormal_pair();
+            let mut corners: Vec<Vec3> = around.iter().map(|&face| face_centers[face]).collect();
+            corners.sort_by(|a, b| {
+                let angle = |p: &Vec3| (p.dot(bitangent)).atan2(p.dot(tangent));
+                angle(a).total_cmp(&angle(b))
+            });
+            if tangent.cross(bitangent).dot(*center) < 0.0 {
+                corners.reverse();
+            }
+
+            for (idx, corner) in corners.iter().enumerate() {
+                let next = corners[(idx + 1) % corners.len()];
+                positions.extend([center.to_array(), corner.to_array(), next.to_array()]);
+            }
+            vertex_counts.push(corners.len() * 3);
+        }
+
+        Ok(Self { positions, vertex_counts, neighbors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_subdivision_gives_twelve_pentagons_among_hexagons() {
+        let goldberg = Goldberg::new(1).unwrap();
+        assert_eq!(goldberg.vertex_counts.len(), 42);
+        assert_eq!(goldberg.vertex_counts.iter().filter(|&&count| count == 5 * 3).count(), 12);
+        assert!(goldberg.vertex_counts.iter().all(|&count| count == 5 * 3 || count == 6 * 3));
+        assert!(goldberg.neighbors.iter().zip(&goldberg.vertex_counts).all(|(list, &count)| list.len() * 3 == count));
+    }
+
+    #[test]
+    fn too_many_subdivisions_is_an_error() {
+        assert!(Goldberg::new(80).is_err());
+    }
+}
\ No newline at end of file
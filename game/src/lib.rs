@@ -3,16 +3,22 @@
 use bevy::prelude::*;
 
 mod components;
+mod goldberg;
 mod plugins;
 mod resources;
+mod spatial;
 mod states;
+mod storage;
 
 mod prelude {
     pub use super::*;
     pub use {components::*, plugins::*, resources::*};
-    pub use crate::states::GameState;
+    pub use crate::states::{GameState, PauseState};
 }
 
+// Read back by the headless integration tests
+pub use resources::{game_rng::{GameRng, GameSeed}, planet_health::PlanetHealth, score::Score};
+
 pub struct AppPlugin;
 
 impl Plugin for AppPlugin {
@@ -20,15 +26,43 @@ impl Plugin for AppPlugin {
         // All custome plugins goes here
         app.add_plugins((
             plugins::defaults::plugin,
+            plugins::balance::plugin,
             // plugins::camera::plugin,
             plugins::game::plugin,
             plugins::menu::plugin,
             plugins::hud::plugin,
             plugins::player::plugin,
             plugins::enemies::plugin,
+            plugins::navigation::plugin,
             plugins::vjoy::plugin,
+            plugins::input::plugin,
+            plugins::settings::plugin,
+            plugins::history::plugin,
+            plugins::snapshot::plugin,
+            plugins::minimap::plugin,
+            plugins::indicators::plugin,
         ));
         #[cfg(feature="dev")]
         app.add_plugins(plugins::debug::plugin);
     }
+}
+
+/// Gameplay simulation without a window, renderer or UI.
+///
+/// Builds the planet on the CPU and runs the `GameState::Playing` systems on
+/// `MinimalPlugins` with a fixed-step clock. Drive it with `App::update` from
+/// tests, or `App::run` for a full run that exits when the run is lost or won.
+pub struct HeadlessPlugin;
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            plugins::headless::plugin,
+            plugins::balance::plugin,
+            plugins::game::plugin,
+            plugins::player::plugin,
+            plugins::enemies::plugin,
+            plugins::navigation::plugin,
+        ));
+    }
 }
\ No newline at end of file
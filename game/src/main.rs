@@ -1,7 +1,13 @@
 use bevy::prelude::*;
+use bevy::log::LogPlugin;
 
-use game::AppPlugin;
+use game::{AppPlugin, HeadlessPlugin};
 
 fn main() {
-    App::new().add_plugins(AppPlugin).run();
+    if std::env::args().any(|arg| arg == "--headless") {
+        // The windowed game gets logging from `DefaultPlugins`
+        App::new().add_plugins((LogPlugin::default(), HeadlessPlugin)).run();
+    } else {
+        App::new().add_plugins(AppPlugin).run();
+    }
 }
\ No newline at end of file
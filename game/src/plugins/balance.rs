@@ -0,0 +1,128 @@
+//! # Balance Plugin
+//!
+//! Loads [PlanetSettings], [PlayerSettings], [EnemySettings] and [DashSettings] from
+//! `assets/config/game.balance.ron`.
+//!
+//! The file is read once while the app is built, so startup systems (like the planet
+//! mesh) already see its values. It is also loaded as an asset, and with the `dev`
+//! feature Bevy's file watcher re-applies it every time it is saved. A file that fails
+//! to parse or validate is reported and the current values are kept.
+//!
+//! The planet's shape (`planet.radius`, `planet.subdivisions`, `planet.tiling`) is only
+//! read at startup: the machines, factories and orbs already placed on the surface
+//! would be left floating or buried, so a reload keeps the current shape.
+
+use bevy::prelude::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+
+use crate::prelude::{
+    balance_config::*,
+    dash_settings::DashSettings,
+    enemy_settings::EnemySettings,
+    planet_settings::PlanetSettings,
+    player_settings::PlayerSettings,
+};
+
+pub(crate) fn plugin(app: &mut App) {
+    let config = read_balance_file().unwrap_or_default();
+
+    app
+        .insert_resource(config.planet)
+        .insert_resource(config.player)
+        .insert_resource(config.enemies)
+        .insert_resource(config.dash)
+        .init_asset::<BalanceConfig>()
+        .register_asset_loader(BalanceLoader)
+        .add_systems(Startup, load_balance_asset)
+        .add_systems(Update, apply_balance_system);
+}
+
+/// Keeps the balance asset loaded so it gets reloaded on change.
+#[derive(Resource)]
+struct BalanceHandle(#[allow(dead_code)] Handle<BalanceConfig>);
+
+#[derive(Default, TypePath)]
+struct BalanceLoader;
+
+impl AssetLoader for BalanceLoader {
+    type Asset = BalanceConfig;
+    type Settings = ();
+    type Error = BalanceError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<BalanceConfig, BalanceError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(|e| BalanceError::Io(e.to_string()))?;
+        BalanceConfig::from_ron(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["balance.ron"]
+    }
+}
+
+/// Reads the balance file straight from disk. `None` (after logging why) if it is
+/// missing or invalid. The web build has no file system and waits for the asset instead.
+fn read_balance_file() -> Option<BalanceConfig> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let path = bevy::asset::io::file::FileAssetReader::get_base_path().join("assets").join(BALANCE_ASSET);
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                warn!("BALANCE: using built-in values, can't read {}: {}", path.display(), e);
+                return None;
+            }
+        };
+
+        match BalanceConfig::from_ron(&bytes) {
+            Ok(config) => Some(config),
+            Err(e) => {
+                warn!("BALANCE: using built-in values, {}", e);
+                None
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    None
+}
+
+fn load_balance_asset(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(BalanceHandle(asset_server.load(BALANCE_ASSET)));
+}
+
+/// Copies a (re)loaded balance file into the settings resources, keeping the values
+/// that belong to the current run.
+fn apply_balance_system(
+    mut asset_msgs: MessageReader<AssetEvent<BalanceConfig>>,
+    configs: Res<Assets<BalanceConfig>>,
+    mut planet: ResMut<PlanetSettings>,
+    mut player: ResMut<PlayerSettings>,
+    mut enemies: ResMut<EnemySettings>,
+    mut dash: ResMut<DashSettings>,
+) {
+    let Some(config) = asset_msgs.read()
+        .filter_map(|msg| match msg {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => configs.get(*id),
+            _ => None,
+        })
+        .last() else { return; };
+
+    let PlanetSettings { radius, subdivisions, tiling, .. } = *planet;
+    let player_radius = player.player_radius;
+    let difficulty_scale = enemies.difficulty_scale;
+    let factory_elapsed = enemies.factory_spawn_timer.elapsed();
+
+    *planet = PlanetSettings { radius, subdivisions, tiling, ..config.planet.clone() };
+    *player = PlayerSettings { player_radius, ..config.player.clone() };
+    *enemies = EnemySettings { difficulty_scale, ..config.enemies.clone() };
+    enemies.factory_spawn_timer.set_elapsed(factory_elapsed);
+    *dash = config.dash.clone();
+
+    info!("BALANCE: applied {}", BALANCE_ASSET);
+}
\ No newline at end of file
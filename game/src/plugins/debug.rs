@@ -18,6 +18,7 @@ pub(crate) fn plugin(app: &mut App) {
         use crate::resources::vjoy_output::VjoyOutput;
         use crate::resources::enemy_settings::EnemySettings;
         use crate::prelude::player_settings::{PlayerSettings, *};
+        use crate::prelude::user_preferences::UserPreferences;
         
         app.add_plugins(EguiPlugin::default());
         
@@ -30,5 +31,7 @@ pub(crate) fn plugin(app: &mut App) {
 
         app.add_plugins(ResourceInspectorPlugin::<DashSettings>::default());
         app.add_plugins(ResourceInspectorPlugin::<DashState>::default());
+
+        app.add_plugins(ResourceInspectorPlugin::<UserPreferences>::default());
     }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use std::collections::HashMap;
+
 use rand::Rng;
 
 use crate::prelude::planet_settings::*;
@@ -19,6 +21,10 @@ use crate::prelude::player_profile::*;
 use crate::prelude::reset_target::*;
 use crate::prelude::notification_timer::*;
 use crate::prelude::player_settings::*;
+use crate::prelude::game_rng::*;
+use crate::prelude::tile_changes::*;
+use crate::prelude::flow_field::*;
+use crate::plugins::game::TileWriter;
 
 use crate::prelude::*;
 
@@ -26,42 +32,96 @@ pub(crate) fn plugin(app: &mut App) {
     app
         .init_resource::<EnemySettings>()
         .register_type::<EnemySettings>()
+        .add_message::<FactoryDestroyed>()
+        .add_message::<KamikazeContact>()
         .add_systems(OnEnter(GameState::Playing), (
-            spawn_factories,
+            spawn_factories.after(crate::plugins::game::seed_session_rng),
         ).chain())        
         .add_systems(Update, (
-            pollution_lifecycle_system,
-            factory_spawner_system, 
-            alien_ai_system, 
+            pollution_lifecycle_system.in_set(RngDraw::Pollution),
+            factory_spawner_system.in_set(RngDraw::FactorySpawner),
+            alien_ai_system.in_set(RngDraw::AlienAi),
+            polluter_trail_system,
+            kamikaze_system,
+            repair_drone_heal_system,
+            factory_regen_system,
+            factory_damage_visuals_system,
+            (spawn_shockwave_system, shockwave_system).chain(),
             billboard_system,
-            factory_director_system,
-        ).run_if(in_state(GameState::Playing)).run_if(any_with_component::<PlayerBall>));
+            factory_director_system.in_set(RngDraw::FactoryDirector),
+        ).run_if(in_state(PauseState::Running)).run_if(any_with_component::<PlayerBall>));
+}
+
+/// Tint of a factory at each damage stage, from untouched to nearly destroyed.
+const DAMAGE_STAGE_TINTS: [Color; 3] = [
+    Color::WHITE,
+    Color::srgb(1.0, 0.7, 0.4),
+    Color::srgb(1.0, 0.35, 0.3),
+];
+
+/// Billboard quad and material shared by every factory.
+pub fn factory_assets(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    (
+        meshes.add(Rectangle::new(12.0, 12.0)),
+        factory_material(materials, asset_server, DAMAGE_STAGE_TINTS[0]),
+    )
+}
+
+fn factory_material(
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+    tint: Color,
+) -> Handle<StandardMaterial> {
+    materials.add(StandardMaterial {
+        base_color: tint,
+        base_color_texture: Some(asset_server.load("textures/factory.png")),
+        alpha_mode: AlphaMode::Mask(0.5),
+        cull_mode: None,
+        unlit: true,
+        ..default()
+    })
+}
+
+/// Billboard quad and material shared by every machine of one kind.
+pub fn machine_assets(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+    archetype: &MachineArchetype,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    (
+        meshes.add(Rectangle::new(archetype.size, archetype.size)),
+        materials.add(StandardMaterial {
+            base_color: archetype.color(),
+            base_color_texture: Some(asset_server.load(archetype.sprite.clone())),
+            alpha_mode: AlphaMode::Mask(0.5),
+            cull_mode: None,
+            unlit: true,
+            ..default()
+        }),
+    )
 }
 
-fn spawn_factories(
+pub fn spawn_factories(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>, 
     mut materials: ResMut<Assets<StandardMaterial>>,
     settings: Res<PlanetSettings>,
     enemy_settings: Res<EnemySettings>,
-    mut q_planet: Query<(&mut PlanetData, &Mesh3d, &Transform), With<Planet>>,
+    q_planet: Query<&Transform, With<Planet>>,
+    mut tiles: TileWriter,
+    mut rng: ResMut<GameRng>,
 ) {
-    let factory_texture = asset_server.load("textures/factory.png");
     let factory_height = 12.0;
+    let (mesh_2d, material_factory) = factory_assets(&mut meshes, &mut materials, &asset_server);
 
-    let material_factory = materials.add(StandardMaterial {
-        base_color_texture: Some(factory_texture),
-        alpha_mode: AlphaMode::Mask(0.5), 
-        unlit: true,
-        cull_mode: None,
-        ..default()
-    });
-    let mesh_2d = meshes.add(Rectangle::new(12.0, factory_height));
-
-    let Ok((mut planet_data, mesh_handle, planet_transform)) = q_planet.single_mut() else { return; };
-    let Some(mesh) = meshes.get_mut(mesh_handle) else { return; };
-    let mut rng = rand::rng();
+    let Ok(planet_transform) = q_planet.single() else { return; };
+    let rng = &mut rng.0;
 
     for _ in 0..enemy_settings.factory_count {
         let theta = rng.random_range(0.0..std::f32::consts::TAU);
@@ -76,6 +136,8 @@ fn spawn_factories(
             FactorySpawner { 
                 timer: Timer::from_seconds(enemy_settings.machine_spawn_interval, TimerMode::Repeating) 
             },
+            Health::full(enemy_settings.factory_max_hp),
+            DamageStage::default(),
             Mesh3d(mesh_2d.clone()),
             MeshMaterial3d(material_factory.clone()),
             Transform::from_translation(spawn_pos)
@@ -85,8 +147,7 @@ fn spawn_factories(
 
         let stain_pos = normal * settings.radius;
         pollute_area(
-            &mut planet_data, 
-            mesh, 
+            &mut tiles, 
             stain_pos - planet_transform.translation, 
             enemy_settings.pollution_radius / settings.radius,
         );
@@ -94,28 +155,13 @@ fn spawn_factories(
 }
 
 fn pollute_area(
-    data: &mut PlanetData,
-    mesh: &mut Mesh,
+    tiles: &mut TileWriter,
     local_pos: Vec3,
     radius_normalized: f32,
 ) {
-    let radius_sq = radius_normalized * radius_normalized;
-    let Some(bevy::mesh::VertexAttributeValues::Float32x3(v_pos_attr)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else { return; };
-    let v_pos = v_pos_attr.clone();
-
-    if let Some(bevy::mesh::VertexAttributeValues::Float32x2(v_uv)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
-        for i in 0..v_pos.len() {
-            let v = Vec3::from(v_pos[i]);
-            if v.distance_squared(local_pos.normalize()) < radius_sq {
-                data.vertex_states[i] = TileState::Polluted;
-                
-                let tri_start = (i / 3) * 3;
-                v_uv[tri_start]     = [0.0, 0.5];
-                v_uv[tri_start + 1] = [0.5, 0.5];
-                v_uv[tri_start + 2] = [0.25, 1.0];
-            }
-        }
-    }
+    let Some(planet) = tiles.tiles() else { return; };
+    let area = planet.within(local_pos.normalize(), radius_normalized);
+    tiles.set_many(area, TileState::Polluted, TileChangeCause::FactorySpawn);
 }
 
 
@@ -124,64 +170,55 @@ fn pollution_lifecycle_system(
     enemy_settings: Res<EnemySettings>,
     settings: Res<PlanetSettings>,
     q_factories: Query<&Transform, With<AlienFactory>>,
-    mut q_planet: Query<(&Mesh3d, &mut PlanetData), With<Planet>>,
-    mut meshes: ResMut<Assets<Mesh>>,
+    mut writer: TileWriter,
+    mut rng: ResMut<GameRng>,
     mut timer: Local<f32>,
 ) {
-    let Ok((mesh_handle, mut planet_data)) = q_planet.single_mut() else { return; };
-    if planet_data.adjacency.is_empty() { return; }
+    let Some(tiles) = writer.tiles() else { return; };
+    if tiles.is_empty() { return; }
 
     *timer += time.delta_secs();
     if *timer < enemy_settings.spread_tick_rate { return; } 
     *timer = 0.0;
-
-    let Some(mesh) = meshes.get_mut(mesh_handle) else { return; };
-    let Some(bevy::mesh::VertexAttributeValues::Float32x3(v_pos_attr)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else { return; };
     
     let factory_positions: Vec<Vec3> = q_factories.iter().map(|t| t.translation).collect();
-    let root_radius_sq = (enemy_settings.pollution_radius * 0.5).powi(2);
-    let connect_radius_sq = (enemy_settings.pollution_radius * 1.2).powi(2);
+    let root_radius = enemy_settings.pollution_radius * 0.5 / settings.radius;
+    let connect_radius = enemy_settings.pollution_radius * 1.2 / settings.radius;
 
     let mut to_infect = std::collections::HashSet::new();
-    let mut rng = rand::rng();
+    let rng = &mut rng.0;
 
     for &f_pos in &factory_positions {
         let mut factory_has_pollution = false;
-        let mut root_vertices = Vec::new();
-        for (idx, v) in v_pos_attr.iter().enumerate() {
-            let world_v_pos = Vec3::from(*v) * settings.radius;
-            if f_pos.distance_squared(world_v_pos) < root_radius_sq {
-                root_vertices.push(idx);
-                if planet_data.vertex_states[idx] == TileState::Polluted {
-                    factory_has_pollution = true;
-                    break; 
-                }
+        let mut root_tiles = Vec::new();
+        for idx in tiles.within(f_pos / settings.radius, root_radius) {
+            root_tiles.push(idx);
+            if tiles.state(idx) == TileState::Polluted {
+                factory_has_pollution = true;
+                break; 
             }
         }
-        if !factory_has_pollution && !root_vertices.is_empty() {
-            if rand::random::<f32>() < 0.2 {
-                for &idx in &root_vertices {
-                    to_infect.insert(idx);
-                    for &sibling in &planet_data.adjacency[idx] { to_infect.insert(sibling); }
-                }
+        if !factory_has_pollution && !root_tiles.is_empty() && rng.random::<f32>() < 0.2 {
+            for &idx in &root_tiles {
+                to_infect.insert(idx);
+                for &sibling in &tiles.tiles[idx].neighbors { to_infect.insert(sibling); }
             }
         }
     }
 
-    let mut is_active = vec![false; planet_data.vertex_states.len()];
+    let mut is_active = vec![false; tiles.len()];
     let mut active_queue = std::collections::VecDeque::new();
-    for (idx, state) in planet_data.vertex_states.iter().enumerate() {
-        if *state == TileState::Polluted {
-            let world_v_pos = Vec3::from(v_pos_attr[idx]) * settings.radius;
-            if factory_positions.iter().any(|f| f.distance_squared(world_v_pos) < connect_radius_sq) {
+    for &f_pos in &factory_positions {
+        for idx in tiles.within(f_pos / settings.radius, connect_radius) {
+            if !is_active[idx] && tiles.state(idx) == TileState::Polluted {
                 is_active[idx] = true;
                 active_queue.push_back(idx);
             }
         }
     }
     while let Some(curr) = active_queue.pop_front() {
-        for &n in &planet_data.adjacency[curr] {
-            if !is_active[n] && planet_data.vertex_states[n] == TileState::Polluted {
+        for &n in &tiles.tiles[curr].neighbors {
+            if !is_active[n] && tiles.state(n) == TileState::Polluted {
                 is_active[n] = true;
                 active_queue.push_back(n);
             }
@@ -190,26 +227,17 @@ fn pollution_lifecycle_system(
 
     for (idx, active) in is_active.iter().enumerate() {
         if !*active { continue; }
-        let neighbors = &planet_data.adjacency[idx];
-        let targets: Vec<&usize> = neighbors.iter().filter(|&&n| planet_data.vertex_states[n] != TileState::Polluted).collect();
+        let neighbors = &tiles.tiles[idx].neighbors;
+        let targets: Vec<&usize> = neighbors.iter().filter(|&&n| tiles.state(n) != TileState::Polluted).collect();
         if targets.is_empty() { continue; }
         let &target_idx = targets[rng.random_range(0..targets.len())];
-        if rand::random::<f32>() < enemy_settings.natural_spread_chance {
+        if rng.random::<f32>() < enemy_settings.natural_spread_chance {
             to_infect.insert(target_idx);
-            for &sibling in &planet_data.adjacency[target_idx] { to_infect.insert(sibling); }
+            for &sibling in &tiles.tiles[target_idx].neighbors { to_infect.insert(sibling); }
         }
     }
 
-    if let Some(bevy::mesh::VertexAttributeValues::Float32x2(v_uv)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
-        for idx in to_infect {
-            planet_data.vertex_states[idx] = TileState::Polluted;
-            
-            let tri_start = (idx / 3) * 3;
-            v_uv[tri_start]     = [0.0, 0.5];
-            v_uv[tri_start + 1] = [0.5, 0.5];
-            v_uv[tri_start + 2] = [0.25, 1.0];
-        }
-    }
+    writer.set_many(to_infect, TileState::Polluted, TileChangeCause::Spread);
 }
 
 fn factory_spawner_system(
@@ -221,27 +249,16 @@ fn factory_spawner_system(
     asset_server: Res<AssetServer>,
     mut q_factories: Query<(&Transform, &mut FactorySpawner), With<AlienFactory>>,
     mut commands: Commands,
-    mut local_assets: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
+    mut rng: ResMut<GameRng>,
+    mut local_assets: Local<HashMap<MachineKind, (Handle<Mesh>, Handle<StandardMaterial>)>>,
 ) {
-    let (machine_mesh, machine_mat) = local_assets.get_or_insert_with(|| {
-        let mesh = meshes.add(Rectangle::new(6.0, 6.0));
-        let tex = asset_server.load("textures/machine.png");
-        let mat = materials.add(StandardMaterial {
-            base_color_texture: Some(tex),
-            alpha_mode: AlphaMode::Mask(0.5),
-            cull_mode: None,
-            unlit: true,
-            ..default()
-        });
-        (mesh, mat)
-    }).clone();
+    let rng = &mut rng.0;
 
     for (f_transform, mut spawner) in q_factories.iter_mut() {
         spawner.timer.set_duration(std::time::Duration::from_secs_f32(enemy_settings.machine_spawn_interval));
         spawner.timer.tick(time.delta());
 
         if spawner.timer.just_finished() {
-            let mut rng = rand::rng();
             let factory_pos = f_transform.translation;
             let normal = factory_pos.normalize();
 
@@ -254,8 +271,16 @@ fn factory_spawner_system(
             let tangent = (random_vec - normal * random_vec.dot(normal)).normalize();
             let spawn_pos = (factory_pos + tangent * 15.0).normalize() * (settings.radius + 3.0);
 
+            let kind = enemy_settings.pick_machine_kind(rng.random());
+            let (machine_mesh, machine_mat) = local_assets
+                .entry(kind)
+                .or_insert_with(|| machine_assets(&mut meshes, &mut materials, &asset_server, enemy_settings.archetypes.get(kind)))
+                .clone();
+
             commands.spawn((
-                AlienMachine { velocity: Vec3::ZERO },
+                AlienMachine::new(Vec3::ZERO),
+                EnemyBrain::new(factory_pos),
+                kind,
                 Mesh3d(machine_mesh.clone()),
                 MeshMaterial3d(machine_mat.clone()),
                 Transform::from_translation(spawn_pos),
@@ -264,33 +289,80 @@ fn factory_spawner_system(
     }
 }
 
-fn alien_ai_system(
+pub fn alien_ai_system(
     time: Res<Time>,
     settings: Res<PlanetSettings>,
     enemy_settings: Res<EnemySettings>,
+    dash_state: Res<DashState>,
+    flow_field: Res<FlowField>,
     q_player: Query<&GlobalTransform, With<PlayerBall>>,
-    mut q_machines: Query<(&mut Transform, &mut AlienMachine)>,
+    q_planet: Query<&PlanetTiles, With<Planet>>,
+    q_factories: Query<(&Transform, &Health), (With<AlienFactory>, Without<AlienMachine>)>,
+    mut q_machines: Query<(Entity, &mut Transform, &mut AlienMachine, &mut EnemyBrain, &MachineKind)>,
+    mut rng: ResMut<GameRng>,
 ) {
     let Ok(player_gtrans) = q_player.single() else { return; };
     let player_pos = player_gtrans.translation();
     let dt = time.delta_secs();
+    let planet = q_planet.single().ok();
 
-    for (mut transform, mut machine) in q_machines.iter_mut() {
+    let positions: Vec<(Entity, Vec3)> = q_machines.iter().map(|(entity, transform, ..)| (entity, transform.translation)).collect();
+
+    for (entity, mut transform, mut machine, mut brain, kind) in q_machines.iter_mut() {
+        if machine.stun > 0.0 {
+            machine.stun -= dt;
+            machine.velocity = Vec3::ZERO;
+            continue;
+        }
+
+        let archetype = enemy_settings.archetypes.get(*kind);
         let machine_pos = transform.translation;
-        let dist = machine_pos.distance(player_pos);
+        let normal = machine_pos.normalize();
 
-        if dist < enemy_settings.machine_detection_range && dist > 5.0 {
-            let normal = machine_pos.normalize();
-            
-            let to_player = player_pos - machine_pos;
-            let tangent_dir = (to_player - normal * to_player.dot(normal)).normalize();
+        if *kind == MachineKind::Repair
+            && let Some(factory_pos) = repair_target(machine_pos, &q_factories)
+        {
+            brain.home = factory_pos;
+        }
 
-            machine.velocity += tangent_dir * enemy_settings.machine_acceleration * dt;
+        brain.timer += dt;
+        let player_dist = machine_pos.distance(player_pos);
+        let detection_range = enemy_settings.machine_detection_range * archetype.detection_range;
+        think(&mut brain, &enemy_settings, &mut rng.0, BrainInput {
+            machine_pos,
+            sees_player: player_dist < detection_range,
+            lost_player: player_dist > detection_range * enemy_settings.chase_leash,
+            player_dashing: dash_state.is_active,
+        });
+
+        let steer = match brain.state {
+            BrainState::Idle => Vec3::ZERO,
+            BrainState::Patrol => tangent_toward(normal, brain.waypoint - machine_pos),
+            BrainState::Chase if player_dist > 5.0 => {
+                chase_route(planet, &flow_field, normal, enemy_settings.path_lookahead)
+                    .map(|waypoint| tangent_toward(normal, waypoint * settings.radius - machine_pos))
+                    .unwrap_or_else(|| tangent_toward(normal, player_pos - machine_pos))
+            }
+            BrainState::Chase => Vec3::ZERO,
+            BrainState::Flee => tangent_toward(normal, machine_pos - player_pos),
+            BrainState::Return => tangent_toward(normal, brain.home - machine_pos),
+        };
+        machine.velocity += steer * enemy_settings.machine_acceleration * archetype.acceleration * dt;
+
+        let mut push = Vec3::ZERO;
+        for &(other, other_pos) in &positions {
+            if other == entity { continue; }
+            let away = machine_pos - other_pos;
+            let dist = away.length();
+            if dist >= enemy_settings.separation_radius || dist < 0.001 { continue; }
+            push += away / dist * (1.0 - dist / enemy_settings.separation_radius);
         }
+        machine.velocity += (push - normal * push.dot(normal)) * enemy_settings.separation_strength * dt;
 
+        let max_speed = enemy_settings.machine_speed * archetype.speed;
         machine.velocity *= 0.95f32.powf(dt * 60.0);
-        if machine.velocity.length() > enemy_settings.machine_speed {
-            machine.velocity = machine.velocity.normalize() * enemy_settings.machine_speed;
+        if machine.velocity.length() > max_speed {
+            machine.velocity = machine.velocity.normalize_or_zero() * max_speed;
         }
 
         transform.translation += machine.velocity * dt;
@@ -298,6 +370,266 @@ fn alien_ai_system(
     }
 }
 
+struct BrainInput {
+    machine_pos: Vec3,
+    sees_player: bool,
+    lost_player: bool,
+    player_dashing: bool,
+}
+
+fn think(brain: &mut EnemyBrain, enemy_settings: &EnemySettings, rng: &mut impl Rng, input: BrainInput) {
+    // Every machine on the planet scatters while the player dashes
+    if input.player_dashing {
+        brain.enter(BrainState::Flee);
+        return;
+    }
+
+    let home_dist = input.machine_pos.distance(brain.home);
+    match brain.state {
+        BrainState::Flee if input.sees_player => brain.enter(BrainState::Chase),
+        BrainState::Flee => brain.enter(BrainState::Return),
+        BrainState::Chase if input.lost_player => brain.enter(BrainState::Return),
+        BrainState::Chase => {}
+        _ if input.sees_player => brain.enter(BrainState::Chase),
+        BrainState::Return if home_dist < enemy_settings.patrol_radius => brain.enter(BrainState::Idle),
+        BrainState::Return => {}
+        _ if home_dist > enemy_settings.patrol_radius * 2.0 => brain.enter(BrainState::Return),
+        BrainState::Idle if brain.timer >= enemy_settings.idle_secs => {
+            let normal = brain.home.normalize();
+            let random_vec = Vec3::new(
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0)
+            );
+            let offset = tangent_toward(normal, random_vec) * rng.random_range(0.3..1.0) * enemy_settings.patrol_radius;
+            brain.waypoint = (brain.home + offset).normalize() * input.machine_pos.length();
+            brain.enter(BrainState::Patrol);
+        }
+        BrainState::Patrol if brain.timer >= enemy_settings.patrol_secs || input.machine_pos.distance(brain.waypoint) < 5.0 => {
+            brain.enter(BrainState::Idle);
+        }
+        BrainState::Idle | BrainState::Patrol => {}
+    }
+}
+
+/// Point on the unit sphere a chasing machine standing over `normal` should head for,
+/// `lookahead` tiles along the flow field. `None` once it shares the player's tile, or
+/// when there is no route, in which case it goes straight for the player.
+fn chase_route(planet: Option<&PlanetTiles>, field: &FlowField, normal: Vec3, lookahead: usize) -> Option<Vec3> {
+    let planet = planet?;
+    if field.tile_count() != planet.len() { return None; }
+    let tile = planet.nearest(normal)?;
+    if Some(tile) == field.goal() { return None; }
+    field.ahead(tile, lookahead.max(1)).map(|ahead| planet.tiles[ahead].centroid)
+}
+
+/// Unit direction along the surface with normal `normal` that best follows `dir`.
+fn tangent_toward(normal: Vec3, dir: Vec3) -> Vec3 {
+    (dir - normal * dir.dot(normal)).normalize_or_zero()
+}
+
+/// Closest damaged factory, or the closest factory if none needs repairs.
+fn repair_target(
+    machine_pos: Vec3,
+    q_factories: &Query<(&Transform, &Health), (With<AlienFactory>, Without<AlienMachine>)>,
+) -> Option<Vec3> {
+    q_factories.iter()
+        .min_by(|(a, a_hp), (b, b_hp)| {
+            b_hp.is_damaged().cmp(&a_hp.is_damaged())
+                .then(machine_pos.distance(a.translation).total_cmp(&machine_pos.distance(b.translation)))
+        })
+        .map(|(transform, _)| transform.translation)
+}
+
+fn polluter_trail_system(
+    time: Res<Time>,
+    settings: Res<PlanetSettings>,
+    enemy_settings: Res<EnemySettings>,
+    mut q_machines: Query<(&Transform, &mut AlienMachine, &MachineKind)>,
+    mut writer: TileWriter,
+) {
+    let Some(tiles) = writer.tiles() else { return; };
+    let dt = time.delta_secs();
+    let trail_radius = enemy_settings.archetypes.polluter.size * 0.5 / settings.radius;
+
+    let mut trail = Vec::new();
+    for (transform, mut machine, kind) in q_machines.iter_mut() {
+        if *kind != MachineKind::Polluter || machine.stun > 0.0 { continue; }
+
+        machine.cooldown -= dt;
+        if machine.cooldown > 0.0 { continue; }
+        machine.cooldown = enemy_settings.polluter_trail_interval;
+
+        trail.extend(tiles.within(transform.translation.normalize(), trail_radius));
+    }
+
+    writer.set_many(trail, TileState::Polluted, TileChangeCause::Machine);
+}
+
+fn kamikaze_system(
+    mut commands: Commands,
+    settings: Res<PlanetSettings>,
+    enemy_settings: Res<EnemySettings>,
+    player_settings: Res<PlayerSettings>,
+    dash_state: Res<DashState>,
+    mut contacts: MessageReader<KamikazeContact>,
+    mut q_player: Query<(&GlobalTransform, &mut PlayerBall)>,
+    q_machines: Query<(Entity, &Transform, &AlienMachine, &MachineKind)>,
+    mut writer: TileWriter,
+) {
+    let touched: Vec<Entity> = contacts.read().map(|contact| contact.machine).collect();
+
+    // A dash goes through a kamikaze before it can go off; the collision system destroys it.
+    if dash_state.is_active { return; }
+    let Ok((player_gtrans, mut player)) = q_player.single_mut() else { return; };
+    let player_pos = player_gtrans.translation();
+    let archetype = &enemy_settings.archetypes.kamikaze;
+
+    for (entity, transform, machine, kind) in q_machines.iter() {
+        if *kind != MachineKind::Kamikaze || machine.stun > 0.0 { continue; }
+        let blast_pos = transform.translation;
+        let gap = (blast_pos.distance(player_pos) - player_settings.player_radius).max(0.0);
+        if gap > enemy_settings.kamikaze_trigger_range && !touched.contains(&entity) { continue; }
+
+        // Full damage on contact, none at the edge of the blast
+        let falloff = 1.0 - gap / enemy_settings.kamikaze_blast_radius;
+        if falloff > 0.0 && !player_settings.god_mode && player.invincibility_timer <= 0.0 {
+            player.hp = (player.hp - archetype.contact_damage * falloff).max(0.0);
+            player.invincibility_timer = 5.0;
+        }
+
+        if let Some(tiles) = writer.tiles() {
+            let burst = tiles.within(blast_pos.normalize(), enemy_settings.kamikaze_blast_radius / settings.radius);
+            writer.set_many(burst, TileState::Polluted, TileChangeCause::Machine);
+        }
+
+        commands.entity(entity).despawn_children();
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Repair drones give hit points back to every factory in range.
+fn repair_drone_heal_system(
+    time: Res<Time>,
+    enemy_settings: Res<EnemySettings>,
+    q_machines: Query<(&Transform, &AlienMachine, &MachineKind)>,
+    mut q_factories: Query<(&Transform, &mut Health), With<AlienFactory>>,
+) {
+    let heal = enemy_settings.repair_rate * time.delta_secs();
+
+    for (transform, machine, kind) in q_machines.iter() {
+        if *kind != MachineKind::Repair || machine.stun > 0.0 { continue; }
+
+        for (factory_transform, mut health) in q_factories.iter_mut() {
+            if !health.is_damaged() { continue; }
+            if factory_transform.translation.distance(transform.translation) > enemy_settings.repair_range { continue; }
+            health.current = (health.current + heal).min(health.max);
+        }
+    }
+}
+
+fn factory_regen_system(
+    time: Res<Time>,
+    enemy_settings: Res<EnemySettings>,
+    mut q_factories: Query<&mut Health, With<AlienFactory>>,
+) {
+    let dt = time.delta_secs();
+
+    for mut health in q_factories.iter_mut() {
+        if !health.is_damaged() { continue; }
+        if health.regen_cooldown > 0.0 {
+            health.regen_cooldown -= dt;
+            continue;
+        }
+        health.current = (health.current + enemy_settings.factory_regen_rate * dt).min(health.max);
+    }
+}
+
+/// Tints factories by how damaged they are.
+fn factory_damage_visuals_system(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    mut q_factories: Query<(&Health, &mut DamageStage, &mut MeshMaterial3d<StandardMaterial>), (With<AlienFactory>, Changed<Health>)>,
+    mut stage_materials: Local<Vec<Handle<StandardMaterial>>>,
+) {
+    if q_factories.is_empty() { return; }
+    if stage_materials.is_empty() {
+        *stage_materials = DAMAGE_STAGE_TINTS.iter()
+            .map(|&tint| factory_material(&mut materials, &asset_server, tint))
+            .collect();
+    }
+
+    for (health, mut drawn_stage, mut material) in q_factories.iter_mut() {
+        let stage = health.stage(DAMAGE_STAGE_TINTS.len());
+        if drawn_stage.0 != stage {
+            drawn_stage.0 = stage;
+            material.0 = stage_materials[stage].clone();
+        }
+    }
+}
+
+fn spawn_shockwave_system(
+    mut commands: Commands,
+    mut destroyed: MessageReader<FactoryDestroyed>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut local_mesh: Local<Option<Handle<Mesh>>>,
+) {
+    for event in destroyed.read() {
+        let mesh = local_mesh
+            .get_or_insert_with(|| meshes.add(Sphere::new(1.0).mesh().ico(3).unwrap()))
+            .clone();
+
+        // Each wave fades on its own, so it gets its own material
+        commands.spawn((
+            Shockwave { age: 0.0 },
+            Mesh3d(mesh),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgba(0.6, 1.0, 0.7, 0.5),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            })),
+            Transform::from_translation(event.position).with_scale(Vec3::ZERO),
+            SessionUi,
+        ));
+    }
+}
+
+/// Grows each shockwave to its full radius, turning the polluted tiles it reaches back to wasteland.
+fn shockwave_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<PlanetSettings>,
+    enemy_settings: Res<EnemySettings>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut q_waves: Query<(Entity, &mut Shockwave, &mut Transform, &MeshMaterial3d<StandardMaterial>)>,
+    mut writer: TileWriter,
+) {
+    for (entity, mut wave, mut transform, material) in q_waves.iter_mut() {
+        wave.age += time.delta_secs();
+        let progress = (wave.age / enemy_settings.shockwave_secs).min(1.0);
+        let radius = enemy_settings.shockwave_radius * progress;
+
+        transform.scale = Vec3::splat(radius);
+        if let Some(mat) = materials.get_mut(material) {
+            mat.base_color.set_alpha(0.5 * (1.0 - progress));
+        }
+
+        if let Some(tiles) = writer.tiles() {
+            let cleaned: Vec<usize> = tiles.within(transform.translation / settings.radius, radius / settings.radius)
+                .into_iter()
+                .filter(|&tile| tiles.state(tile) == TileState::Polluted)
+                .collect();
+            writer.set_many(cleaned, TileState::Wasteland, TileChangeCause::Shockwave);
+        }
+
+        if progress >= 1.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 fn billboard_system(
     q_cam: Query<&GlobalTransform, With<BirdEyeCamera>>,
     mut q_billboards: Query<&mut Transform, Or<(With<AlienFactory>, With<AlienMachine>)>>,
@@ -326,9 +658,11 @@ fn factory_director_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     asset_server: Res<AssetServer>,
-    mut q_planet: Query<(&mut PlanetData, &Mesh3d, &Transform), With<Planet>>,
+    q_planet: Query<&Transform, With<Planet>>,
+    mut tiles: TileWriter,
     mut q_notice: Query<(&mut Visibility, &mut NotificationTimer, &Children), With<FactoryNotificationText>>,
     mut q_text_color: Query<&mut TextColor>,
+    mut rng: ResMut<GameRng>,
     mut local_assets: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
 ) {
     let dt = time.delta_secs();
@@ -339,31 +673,24 @@ fn factory_director_system(
     enemy_settings.factory_spawn_timer.tick(time.delta().mul_f32(current_diff));
 
     if enemy_settings.factory_spawn_timer.just_finished() {
-        let mut rng = rand::rng();
+        let rng = &mut rng.0;
         let theta = rng.random_range(0.0..std::f32::consts::TAU);
         let phi = (rng.random_range(-1.0..1.0) as f32).acos();
         let normal = Vec3::new(phi.sin() * theta.cos(), phi.cos(), phi.sin() * theta.sin());
         
         let spawn_pos = normal * (settings.radius + 6.0);
 
-        let (f_mesh, f_mat) = local_assets.get_or_insert_with(|| {
-            (
-                meshes.add(Rectangle::new(12.0, 12.0)), 
-                materials.add(StandardMaterial {
-                    base_color_texture: Some(asset_server.load("textures/factory.png")),
-                    alpha_mode: AlphaMode::Mask(0.5),
-                    cull_mode: None, 
-                    unlit: true,
-                    ..default()
-                })
-            )
-        }).clone();
+        let (f_mesh, f_mat) = local_assets
+            .get_or_insert_with(|| factory_assets(&mut meshes, &mut materials, &asset_server))
+            .clone();
 
         commands.spawn((
             AlienFactory,
             FactorySpawner { 
                 timer: Timer::from_seconds(10.0 / current_diff, TimerMode::Repeating) 
             },
+            Health::full(enemy_settings.factory_max_hp),
+            DamageStage::default(),
             Mesh3d(f_mesh),
             MeshMaterial3d(f_mat),
             Transform::from_translation(spawn_pos).looking_at(spawn_pos + normal, Vec3::Y),
@@ -372,15 +699,12 @@ fn factory_director_system(
             SessionUi,
         ));
 
-        if let Ok((mut planet_data, mesh_handle, planet_transform)) = q_planet.single_mut() {
-            if let Some(mesh) = meshes.get_mut(mesh_handle) {
-                pollute_area(
-                    &mut planet_data, 
-                    mesh, 
-                    spawn_pos - planet_transform.translation, 
-                    enemy_settings.pollution_radius / settings.radius,
-                );
-            }
+        if let Ok(planet_transform) = q_planet.single() {
+            pollute_area(
+                &mut tiles, 
+                spawn_pos - planet_transform.translation, 
+                enemy_settings.pollution_radius / settings.radius,
+            );
         }
         
         if let Ok((mut vis, mut timer, children)) = q_notice.single_mut() {
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 
 use rand::Rng;
 
@@ -18,32 +19,149 @@ use crate::prelude::player_profile::*;
 use crate::prelude::reset_target::*;
 use crate::prelude::notification_timer::*;
 use crate::prelude::player_settings::*;
+use crate::prelude::game_rng::*;
+use crate::prelude::enemy_settings::*;
+use crate::prelude::run_stats::*;
+use crate::prelude::tile_changes::*;
+use crate::prelude::planet_health::*;
+use crate::prelude::minimap_camera::MINIMAP_LAYER;
+use crate::goldberg::Goldberg;
+use bevy::camera::visibility::RenderLayers;
 
 use crate::prelude::*;
 
 pub(crate) fn plugin(app: &mut App) {
     app
         .init_state::<GameState>()
+        .add_sub_state::<PauseState>()
         .init_resource::<PlanetSettings>()
         .init_resource::<Score>()
         .init_resource::<SessionTime>()
+        .init_resource::<RunStats>()
+        .init_resource::<PlanetHealth>()
         .init_resource::<PlayerProfile>()
         .init_resource::<ResetTarget>()
+        .init_resource::<GameRng>()
+        .insert_resource(GameSeed::from_args())
         .add_message::<ScoreMessage>()
+        .add_message::<TileStateChanged>()
         .register_type::<Score>()
+        .register_type::<RunStats>()
+        .register_type::<PlanetHealth>()
         .register_type::<PlanetSettings>()
-        .add_systems(Startup, (setup_planet, build_adjacency).chain())
+        .register_type::<GameSeed>()
+        .configure_sets(Update, (
+            RngDraw::Pollution,
+            RngDraw::FactorySpawner,
+            RngDraw::AlienAi,
+            RngDraw::FactoryDirector,
+            RngDraw::Orbs,
+        ).chain())
+        .add_systems(Startup, setup_planet)
         .add_systems(OnEnter(GameState::Playing), (
+            seed_session_rng,
             spawn_session_objects, 
         ).chain())        
+        // Runs in every state, so changes made by the reset and the snapshot restore are counted too
+        .add_systems(Update, track_planet_health_system.after(tile_restoration_system).before(victory_system))
         .add_systems(Update, (
-            (tile_restoration_system, ),
-            (orb_spawning_system, orb_collection_system, orb_animation_system),
-        ).run_if(in_state(GameState::Playing)).run_if(any_with_component::<PlayerBall>))
+            (tile_restoration_system, tile_score_system, score_event_handler, victory_system).chain(),
+            (track_session_time_system),
+            (orb_spawning_system.in_set(RngDraw::Orbs), orb_collection_system, orb_animation_system),
+        ).run_if(in_state(PauseState::Running)).run_if(any_with_component::<PlayerBall>))
+        .add_systems(PostUpdate, paint_planet_system)
         .add_systems(OnEnter(GameState::Resetting), world_reset_system);
 }
 
-fn setup_planet(
+/// The one way gameplay systems change tile states.
+///
+/// Every tile that actually changes is reported as a [TileStateChanged] message, so
+/// scoring, stats and effects can react to it without touching the planet themselves.
+#[derive(SystemParam)]
+pub struct TileWriter<'w, 's> {
+    planet: Query<'w, 's, &'static mut PlanetTiles, With<Planet>>,
+    changed: MessageWriter<'w, TileStateChanged>,
+}
+
+impl TileWriter<'_, '_> {
+    /// Read access to the planet, e.g. to pick which tiles to change.
+    pub fn tiles(&self) -> Option<&PlanetTiles> {
+        self.planet.single().ok()
+    }
+
+    /// Sets every tile in `tiles` to `state`. Returns how many changed.
+    pub fn set_many(&mut self, tiles: impl IntoIterator<Item = usize>, state: TileState, cause: TileChangeCause) -> usize {
+        self.set_each(tiles.into_iter().map(|tile| (tile, state)), cause)
+    }
+
+    /// Sets each tile to its own state. Returns how many changed.
+    pub fn set_each(&mut self, changes: impl IntoIterator<Item = (usize, TileState)>, cause: TileChangeCause) -> usize {
+        let Ok(mut planet) = self.planet.single_mut() else { return 0; };
+        let mut count = 0;
+        for (tile, to) in changes {
+            let from = planet.set_state(tile, to);
+            if from != to {
+                self.changed.write(TileStateChanged { tile, from, to, cause });
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Sets the whole planet to `state`.
+    pub fn fill(&mut self, state: TileState, cause: TileChangeCause) -> usize {
+        let count = self.tiles().map_or(0, PlanetTiles::len);
+        self.set_many(0..count, state, cause)
+    }
+}
+
+/// Keeps [PlanetHealth] in step with tile changes and the factory count.
+fn track_planet_health_system(
+    mut changes: MessageReader<TileStateChanged>,
+    mut health: ResMut<PlanetHealth>,
+    q_factories: Query<(), With<AlienFactory>>,
+) {
+    let factories = q_factories.iter().count();
+    if changes.is_empty() && factories == health.factories { return; }
+
+    for change in changes.read() {
+        health.apply(change.from, change.to);
+    }
+    health.factories = factories;
+}
+
+/// Repaints the tiles that changed this frame, touching only the chunk meshes they are in.
+fn paint_planet_system(
+    mut changes: MessageReader<TileStateChanged>,
+    q_planet: Query<&PlanetTiles, With<Planet>>,
+    q_chunks: Query<(&PlanetChunk, &Mesh3d)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    if changes.is_empty() { return; }
+    let Ok(tiles) = q_planet.single() else { return; };
+
+    let dirty = tiles.by_chunk(changes.read().map(|change| change.tile));
+
+    for (chunk, mesh_handle) in q_chunks.iter() {
+        let Some(changed) = dirty.get(chunk.0).filter(|changed| !changed.is_empty()) else { continue; };
+        let Some(uvs) = meshes.get_mut(mesh_handle).and_then(planet_uvs) else { continue; };
+        for &tile in changed {
+            tiles.paint_tile(tile, uvs);
+        }
+    }
+}
+
+/// Directions to the centres of the 20 faces of an icosahedron, one chunk each.
+fn icosahedron_face_centers() -> Vec<Vec3> {
+    let mut ico = Sphere::new(1.0).mesh().ico(0).unwrap();
+    ico.duplicate_vertices();
+    let Some(bevy::mesh::VertexAttributeValues::Float32x3(corners)) = ico.attribute(Mesh::ATTRIBUTE_POSITION) else { return Vec::new(); };
+    corners.chunks_exact(3)
+        .map(|face| face.iter().map(|pos| Vec3::from(*pos)).sum::<Vec3>().normalize())
+        .collect()
+}
+
+pub fn setup_planet(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -56,37 +174,62 @@ fn setup_planet(
         Transform::from_xyz(100.0, 100.0, 100.0).looking_at(Vec3::ZERO, Vec3::Y),
     ));
 
-    let mut mesh = Sphere::new(1.0).mesh().ico(planet_settings.subdivisions as u32).unwrap();
-    mesh.duplicate_vertices();
-    mesh.compute_flat_normals();
-    let vertex_count = mesh.count_vertices();
+    let chunk_centers = icosahedron_face_centers();
+    let (tiles, chunk_meshes) = match planet_settings.tiling {
+        PlanetTiling::Triangles => {
+            let mut mesh = match Sphere::new(1.0).mesh().ico(planet_settings.subdivisions) {
+                Ok(mesh) => mesh,
+                Err(e) => {
+                    error!("PLANET: can't build the planet: {}", e);
+                    return;
+                }
+            };
+            mesh.duplicate_vertices();
 
-    let mut uvs = vec![[0.0, 0.0]; vertex_count];
-    for i in (0..vertex_count).step_by(3) {
-        uvs[i]     = [0.0, 0.0];
-        uvs[i + 1] = [0.5, 0.0];
-        uvs[i + 2] = [0.25, 0.5];
-    }
-    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-    
-    mesh.asset_usage = bevy::asset::RenderAssetUsages::default();
+            let Some(bevy::mesh::VertexAttributeValues::Float32x3(v_pos)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else { return; };
+            let tiles = PlanetTiles::from_triangles(v_pos, &chunk_centers);
+            let chunk_meshes = tiles.chunk_meshes(v_pos);
+            (tiles, chunk_meshes)
+        }
+        PlanetTiling::Hexagons => {
+            let goldberg = match Goldberg::new(planet_settings.subdivisions) {
+                Ok(goldberg) => goldberg,
+                Err(e) => {
+                    error!("PLANET: can't build the planet: {}", e);
+                    return;
+                }
+            };
+            let tiles = PlanetTiles::from_polygons(&goldberg.positions, &goldberg.vertex_counts, goldberg.neighbors, &chunk_centers);
+            let chunk_meshes = tiles.chunk_meshes(&goldberg.positions);
+            (tiles, chunk_meshes)
+        }
+    };
 
     let atlas_handle = asset_server.load("textures/tiles.png");
+    let material = materials.add(StandardMaterial { 
+        base_color_texture: Some(atlas_handle),
+        perceptual_roughness: 0.9,
+        ..default() 
+    });
+
+    commands.insert_resource(PlanetHealth::from_tiles(&tiles));
 
     commands.spawn((
         Planet,
-        PlanetData { 
-            vertex_states: vec![TileState::Wasteland; vertex_count],
-            adjacency: Vec::new(), 
-        },
-        Mesh3d(meshes.add(mesh)),
-        MeshMaterial3d(materials.add(StandardMaterial { 
-            base_color_texture: Some(atlas_handle),
-            perceptual_roughness: 0.9,
-            ..default() 
-        })),
+        tiles,
         Transform::from_scale(Vec3::splat(planet_settings.radius)),
-    ));
+        Visibility::default(),
+    ))
+    .with_children(|parent| {
+        for (chunk, chunk_mesh) in chunk_meshes.into_iter().enumerate() {
+            parent.spawn((
+                PlanetChunk(chunk),
+                Mesh3d(meshes.add(chunk_mesh)),
+                MeshMaterial3d(material.clone()),
+                RenderLayers::from_layers(&[0, MINIMAP_LAYER]),
+            ));
+        }
+    });
 
     commands.spawn((
         PlanetPivot,
@@ -104,7 +247,14 @@ fn setup_planet(
     });
 }
 
-fn spawn_session_objects(
+/// Rolls (or takes the pinned) seed for the new run and reseeds [GameRng] from it.
+pub fn seed_session_rng(mut seed: ResMut<GameSeed>, mut rng: ResMut<GameRng>) {
+    seed.current = seed.pinned.take().unwrap_or_else(rand::random);
+    *rng = GameRng::from_seed(seed.current);
+    info!("SEED: {}", seed.current);
+}
+
+pub fn spawn_session_objects(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -134,91 +284,88 @@ fn spawn_session_objects(
     commands.entity(pivot_entity).add_child(ball_entity);
 }
 
-fn build_adjacency(
-    mut q_planet: Query<(&Mesh3d, &mut PlanetData), With<Planet>>,
-    mut meshes: ResMut<Assets<Mesh>>, 
+fn tile_restoration_system(
+    planet_settings: Res<PlanetSettings>,
+    player_settings: Res<PlayerSettings>,
+    q_player: Query<&GlobalTransform, With<PlayerBall>>,
+    q_planet: Query<&Transform, With<Planet>>,
+    mut tiles: TileWriter,
 ) {
-    let Ok((mesh_handle, mut planet_data)) = q_planet.single_mut() else { return; };
-    let Some(mesh) = meshes.get_mut(mesh_handle) else { return; };
-
-    let Some(bevy::mesh::VertexAttributeValues::Float32x3(v_pos)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else { return; };
-    let vertex_count = v_pos.len();
-
-    let mut pos_map: std::collections::HashMap<[i32; 3], Vec<usize>> = std::collections::HashMap::new();
-    for (idx, pos) in v_pos.iter().enumerate() {
-        let key = [(pos[0] * 1000.0) as i32, (pos[1] * 1000.0) as i32, (pos[2] * 1000.0) as i32];
-        pos_map.entry(key).or_default().push(idx);
-    }
-
-    let mut adj = vec![std::collections::HashSet::new(); vertex_count];
+    let Ok(player_gtrans) = q_player.single() else { return; };
+    let Ok(planet_trans) = q_planet.single() else { return; };
+    let Some(planet) = tiles.tiles() else { return; };
 
-    for i in (0..vertex_count).step_by(3) {
-        if i + 2 >= vertex_count { break; }
-        let corners = [i, i + 1, i + 2];
+    let player_rel_pos = (player_gtrans.translation() - planet_trans.translation) / planet_settings.radius;
+    let brush = (player_settings.player_radius * 1.2) / planet_settings.radius;
 
-        for j in 0..3 {
-            let p_start = v_pos[corners[j]];
-            let p_end = v_pos[corners[(j + 1) % 3]];
-            
-            let k_start = [(p_start[0] * 1000.0) as i32, (p_start[1] * 1000.0) as i32, (p_start[2] * 1000.0) as i32];
-            let k_end = [(p_end[0] * 1000.0) as i32, (p_end[1] * 1000.0) as i32, (p_end[2] * 1000.0) as i32];
+    let under_player = planet.within(player_rel_pos, brush);
+    tiles.set_many(under_player, TileState::Healthy, TileChangeCause::Player);
+}
 
-            for &v1 in &pos_map[&k_start] {
-                for &v2 in &pos_map[&k_end] {
-                    adj[v1].insert(v2);
-                    adj[v2].insert(v1);
-                }
-            }
-        }
+/// Awards points for every tile the player restores.
+fn tile_score_system(
+    mut changes: MessageReader<TileStateChanged>,
+    mut score_msg: MessageWriter<ScoreMessage>,
+    mut stats: ResMut<RunStats>,
+) {
+    for change in changes.read() {
+        if change.cause != TileChangeCause::Player || change.to != TileState::Healthy { continue; }
+        let points = match change.from {
+            TileState::Polluted => 200,
+            _ => 100,
+        };
+        score_msg.write(ScoreMessage(points));
+        stats.tiles_restored += 1;
     }
+}
 
-    for siblings in pos_map.values() {
-        for &v1 in siblings {
-            for &v2 in siblings {
-                if v1 != v2 { adj[v1].insert(v2); }
-            }
-        }
+fn score_event_handler(
+    mut messages: MessageReader<ScoreMessage>,
+    mut score: ResMut<Score>,
+) {
+    for msg in messages.read() {
+        score.current += msg.0;
     }
-
-    planet_data.adjacency = adj.into_iter().map(|set| set.into_iter().collect()).collect();
 }
 
-fn tile_restoration_system(
-    planet_settings: Res<PlanetSettings>,
-    player_settings: Res<PlayerSettings>,
-    q_player: Query<&GlobalTransform, With<PlayerBall>>,
-    mut q_planet: Query<(&Transform, &Mesh3d, &mut PlanetData), With<Planet>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut score_msg: MessageWriter<ScoreMessage>,
+/// Ends the run as won once every factory is gone and enough of the planet is Healthy,
+/// adding the time bonus to the score.
+fn victory_system(
+    settings: Res<PlanetSettings>,
+    time: Res<SessionTime>,
+    health: Res<PlanetHealth>,
+    mut score: ResMut<Score>,
+    mut next_state: ResMut<NextState<GameState>>,
 ) {
-    let Ok(player_gtrans) = q_player.single() else { return; };
-    let Ok((planet_trans, mesh_handle, mut planet_data)) = q_planet.single_mut() else { return; };
-    let Some(mesh) = meshes.get_mut(mesh_handle) else { return; };
+    if health.factories > 0 || health.total() == 0 { return; }
+    if health.fraction(TileState::Healthy) < settings.victory_healthy_ratio { return; }
 
-    let player_rel_pos = (player_gtrans.translation() - planet_trans.translation) / planet_settings.radius;
-    let brush_sq = ((player_settings.player_radius * 1.2) / planet_settings.radius).powi(2);
+    score.time_bonus = ((settings.par_time - time.elapsed).max(0.0) * settings.time_bonus_per_sec) as usize;
+    score.current += score.time_bonus;
+    info!("VICTORY: planet restored in {} with a time bonus of {}", time.format(), score.time_bonus);
+    next_state.set(GameState::Victory);
+}
 
-    let Some(bevy::mesh::VertexAttributeValues::Float32x3(v_pos)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else { return; };
-    let v_pos_local = v_pos.clone(); 
+fn track_session_time_system(
+    time: Res<Time>,
+    mut session_time: ResMut<SessionTime>,
+) {
+    session_time.elapsed += time.delta_secs();
+}
 
-    if let Some(bevy::mesh::VertexAttributeValues::Float32x2(v_uv)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
-        for i in 0..v_pos_local.len() {
-            if planet_data.vertex_states[i] == TileState::Healthy { continue; }
-            let v = Vec3::from(v_pos_local[i]);
-            if (v.x - player_rel_pos.x).abs() > 0.1 { continue; }
-            
-            if v.distance_squared(player_rel_pos) < brush_sq {
-                let points = if planet_data.vertex_states[i] == TileState::Polluted { 200 } else { 100 };
-                planet_data.vertex_states[i] = TileState::Healthy;
-                score_msg.write(ScoreMessage(points));
-
-                let tri_start = (i / 3) * 3;
-                v_uv[tri_start]     = [0.5, 0.0];
-                v_uv[tri_start + 1] = [1.0, 0.0];
-                v_uv[tri_start + 2] = [0.75, 0.5];
-            }
-        }
-    }
+/// Glowing sphere mesh and material shared by every energy orb.
+pub fn orb_assets(
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    (
+        meshes.add(Sphere::new(2.0).mesh().ico(4).unwrap()),
+        materials.add(StandardMaterial {
+            base_color: Color::srgba(0.0, 5.0, 1.0, 1.0),
+            emissive: LinearRgba::GREEN * 10.0,
+            ..default()
+        }),
+    )
 }
 
 fn orb_spawning_system(
@@ -226,51 +373,34 @@ fn orb_spawning_system(
     settings: Res<PlanetSettings>,
     mut meshes: ResMut<Assets<Mesh>>, 
     mut materials: ResMut<Assets<StandardMaterial>>,
-    q_planet: Query<(&PlanetData, &Mesh3d), With<Planet>>,
+    q_planet: Query<&PlanetTiles, With<Planet>>,
     q_orbs: Query<Entity, With<EnergyOrb>>,
+    mut rng: ResMut<GameRng>,
     mut local_assets: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
 ) {
     if q_orbs.iter().count() >= settings.max_orbs { return; }
     
-    let mut rng = rand::rng();
+    let rng = &mut rng.0;
     if rng.random::<f32>() > settings.orb_spawn_chance { return; }
 
-    if local_assets.is_none() {
-        let m = meshes.add(Sphere::new(2.0).mesh().ico(4).unwrap());
-        let mat = materials.add(StandardMaterial {
-            base_color: Color::srgba(0.0, 5.0, 1.0, 1.0),
-            emissive: LinearRgba::GREEN * 10.0,
-            ..default()
-        });
-        *local_assets = Some((m, mat));
-    }
-    let (orb_mesh, orb_mat) = local_assets.as_ref().unwrap().clone();
-
-    let Ok((planet_data, mesh_handle)) = q_planet.single() else { return; };
-    let Some(mesh) = meshes.get(mesh_handle) else { return; };
-
-    let healthy_indices: Vec<usize> = planet_data.vertex_states.iter()
-        .enumerate()
-        .filter(|(_, state)| **state == TileState::Healthy)
-        .map(|(idx, _)| idx)
-        .collect();
+    let (orb_mesh, orb_mat) = local_assets
+        .get_or_insert_with(|| orb_assets(&mut meshes, &mut materials))
+        .clone();
 
-    if healthy_indices.is_empty() { return; }
+    let Ok(tiles) = q_planet.single() else { return; };
 
-    let random_idx = healthy_indices[rng.random_range(0..healthy_indices.len())];
+    let healthy: Vec<usize> = tiles.with_state(TileState::Healthy).collect();
+    if healthy.is_empty() { return; }
 
-    if let Some(bevy::mesh::VertexAttributeValues::Float32x3(v_pos)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-        let normal = Vec3::from(v_pos[random_idx]).normalize();
-        
-        let spawn_pos = normal * (settings.radius + 5.0);
+    let normal = tiles.tiles[healthy[rng.random_range(0..healthy.len())]].centroid;
+    let spawn_pos = normal * (settings.radius + 5.0);
 
-        commands.spawn((
-            EnergyOrb,
-            Mesh3d(orb_mesh),
-            MeshMaterial3d(orb_mat),
-            Transform::from_translation(spawn_pos),
-        ));
-    }
+    commands.spawn((
+        EnergyOrb,
+        Mesh3d(orb_mesh),
+        MeshMaterial3d(orb_mat),
+        Transform::from_translation(spawn_pos),
+    ));
 }
 
 fn orb_collection_system(
@@ -279,6 +409,7 @@ fn orb_collection_system(
     player_settings: Res<PlayerSettings>,
     mut q_player: Query<(&GlobalTransform, &mut PlayerBall)>,
     q_orbs: Query<(Entity, &GlobalTransform), With<EnergyOrb>>,
+    mut stats: ResMut<RunStats>,
 ) {
     let Ok((player_gtrans, mut player)) = q_player.single_mut() else { return; };
     let player_pos = player_gtrans.translation();
@@ -288,6 +419,7 @@ fn orb_collection_system(
         
         if player_pos.distance(orb_pos) < player_settings.player_radius + 3.0 {
             player.hp = (player.hp + planet_settings.orb_hp_gain).min(100.0);
+            stats.orbs_collected += 1;
             
             commands.entity(orb_entity).despawn_children();
             commands.entity(orb_entity).despawn();
@@ -313,11 +445,12 @@ fn world_reset_system(
     mut next_state: ResMut<NextState<GameState>>,
     reset_target: Res<ResetTarget>,
     q_cleanup: Query<Entity, Or<(With<PlayerBall>, With<AlienFactory>, With<AlienMachine>, With<EnergyOrb>, With<SessionUi>)>>,
-    mut q_planet: Query<(&mut PlanetData, &Mesh3d), With<Planet>>,
-    mut meshes: ResMut<Assets<Mesh>>,
+    mut tiles: TileWriter,
     mut dash_state: ResMut<DashState>,
+    mut enemy_settings: ResMut<EnemySettings>,
     mut score: ResMut<Score>,
     mut time: ResMut<SessionTime>,
+    mut stats: ResMut<RunStats>,
 ) {
     for entity in q_cleanup.iter() {
         if let Ok(mut entity_cmds) = commands.get_entity(entity) {
@@ -326,24 +459,106 @@ fn world_reset_system(
         }
     }
 
-    if let Ok((mut planet_data, mesh_handle)) = q_planet.single_mut() {
-        planet_data.vertex_states.fill(TileState::Wasteland);
-
-        if let Some(mesh) = meshes.get_mut(mesh_handle) {
-            if let Some(bevy::mesh::VertexAttributeValues::Float32x2(v_uv)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
-                for i in (0..v_uv.len()).step_by(3) {
-                    v_uv[i]     = [0.0, 0.0];
-                    v_uv[i + 1] = [0.5, 0.0];
-                    v_uv[i + 2] = [0.25, 0.5];
-                }
-            }
-        }
-    }
+    tiles.fill(TileState::Wasteland, TileChangeCause::Reset);
 
     *dash_state = DashState::default();
     dash_state.current_energy = 100.0;
-    score.current = 0;
+    enemy_settings.difficulty_scale = 1.0;
+    enemy_settings.factory_spawn_timer.reset();
+    *score = Score::default();
     time.elapsed = 0.0;
+    *stats = RunStats::default();
 
     next_state.set(reset_target.0.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// An app holding a planet of `count` triangle tiles, all Wasteland.
+    fn planet_app(count: usize) -> App {
+        let positions: Vec<[f32; 3]> = (0..count * 3).map(|vertex| [vertex as f32, 1.0, 0.0]).collect();
+        let tiles = PlanetTiles::from_polygons(&positions, &vec![3; count], vec![Vec::new(); count], &[]);
+
+        let mut app = App::new();
+        app.add_message::<TileStateChanged>();
+        app.world_mut().spawn((Planet, tiles));
+        app
+    }
+
+    fn write_tiles(app: &mut App, change: impl FnOnce(&mut TileWriter) -> usize + Send + Sync + 'static) -> usize {
+        let mut change = Some(change);
+        app.world_mut().run_system_once(move |mut tiles: TileWriter| change.take().map_or(0, |change| change(&mut tiles))).unwrap()
+    }
+
+    fn reported(app: &mut App) -> Vec<(usize, TileState, TileState)> {
+        app.world_mut().resource_mut::<Messages<TileStateChanged>>()
+            .drain()
+            .map(|change| (change.tile, change.from, change.to))
+            .collect()
+    }
+
+    #[test]
+    fn tile_writer_reports_only_tiles_that_change() {
+        use TileState::*;
+        let mut app = planet_app(4);
+
+        assert_eq!(write_tiles(&mut app, |tiles| tiles.set_many([0, 1, 1], Polluted, TileChangeCause::Spread)), 2);
+        assert_eq!(reported(&mut app), [(0, Wasteland, Polluted), (1, Wasteland, Polluted)]);
+
+        assert_eq!(write_tiles(&mut app, |tiles| tiles.set_each([(0, Polluted), (1, Healthy), (2, Wasteland)], TileChangeCause::Player)), 1);
+        assert_eq!(reported(&mut app), [(1, Polluted, Healthy)]);
+
+        assert_eq!(write_tiles(&mut app, |tiles| tiles.fill(Healthy, TileChangeCause::Reset)), 3);
+        assert_eq!(reported(&mut app), [(0, Polluted, Healthy), (2, Wasteland, Healthy), (3, Wasteland, Healthy)]);
+
+        let planet = app.world_mut().query::<&PlanetTiles>().single(app.world()).unwrap();
+        assert!(planet.tiles.iter().all(|tile| tile.state == Healthy));
+    }
+
+    /// Runs [victory_system] once on a planet whose tiles and factories are `health`,
+    /// `elapsed` seconds into a run. Returns the score and whether the run was won.
+    fn check_victory(health: PlanetHealth, elapsed: f32) -> (Score, bool) {
+        let mut app = App::new();
+        app.init_resource::<PlanetSettings>()
+            .init_resource::<NextState<GameState>>()
+            .insert_resource(SessionTime { elapsed })
+            .insert_resource(health)
+            .insert_resource(Score { current: 500, time_bonus: 0 });
+        app.world_mut().run_system_once(victory_system).unwrap();
+
+        let won = matches!(*app.world().resource::<NextState<GameState>>(), NextState::Pending(GameState::Victory));
+        (app.world_mut().remove_resource::<Score>().unwrap(), won)
+    }
+
+    #[test]
+    fn restoring_every_tile_wins_with_the_time_bonus() {
+        let settings = PlanetSettings::default();
+        let restored = PlanetHealth { healthy: 80, ..default() };
+
+        let (score, won) = check_victory(restored, 100.0);
+        assert!(won);
+        assert_eq!(score.time_bonus, ((settings.par_time - 100.0) * settings.time_bonus_per_sec) as usize);
+        assert_eq!(score.current, 500 + score.time_bonus);
+
+        let (score, won) = check_victory(restored, settings.par_time + 1.0);
+        assert!(won);
+        assert_eq!((score.current, score.time_bonus), (500, 0));
+    }
+
+    #[test]
+    fn no_victory_while_factories_stand_or_the_planet_is_empty() {
+        for health in [
+            PlanetHealth { healthy: 80, factories: 1, ..default() },
+            PlanetHealth { healthy: 8, polluted: 72, ..default() },
+            PlanetHealth::default(),
+        ] {
+            let (score, won) = check_victory(health, 100.0);
+            assert!(!won, "{:?}", health);
+            assert_eq!((score.current, score.time_bonus), (500, 0));
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::ecs::schedule::ExecutorKind;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::prelude::*;
+use crate::prelude::vjoy_output::*;
+use crate::prelude::dash_settings::*;
+use crate::prelude::dash_state::*;
+use crate::prelude::score::*;
+use crate::prelude::session_time::*;
+use crate::prelude::game_rng::*;
+
+/// Simulated length of one `App::update` in headless runs.
+pub(crate) const HEADLESS_TIMESTEP: Duration = Duration::from_nanos(16_666_667);
+
+// Replaces `defaults::plugin` when there is no window or GPU: only the engine pieces the
+// gameplay systems need, a fake clock advancing by a fixed step, and a run that starts
+// straight in `GameState::Playing` and quits on game over or victory.
+// Update also runs single threaded, so the systems outside the `RngDraw` chain keep one
+// order as well and a pinned seed replays a run exactly.
+
+pub(crate) fn plugin(app: &mut App) {
+    app.add_plugins((
+            MinimalPlugins,
+            TransformPlugin,
+            StatesPlugin,
+            AssetPlugin::default(),
+        ))
+        .init_asset::<Mesh>()
+        .init_asset::<Image>()
+        .init_asset::<StandardMaterial>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(HEADLESS_TIMESTEP))
+        .edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        })
+        // Normally owned by `vjoy::plugin`, which is UI only
+        .init_resource::<VjoyOutput>()
+        .init_resource::<DashState>()
+        .init_resource::<DashSettings>()
+        .add_systems(Startup, start_simulation)
+        .add_systems(OnEnter(GameState::GameOver), report_and_exit)
+        .add_systems(OnEnter(GameState::Victory), report_and_exit);
+}
+
+fn start_simulation(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Playing);
+}
+
+fn report_and_exit(
+    score: Res<Score>,
+    time: Res<SessionTime>,
+    seed: Res<GameSeed>,
+    state: Res<State<GameState>>,
+    mut exit_events: MessageWriter<AppExit>,
+) {
+    let outcome = if *state.get() == GameState::Victory { "won" } else { "lost" };
+    info!("HEADLESS: seed {} | score {} | time {} | {}", seed.current, score.current, time.format(), outcome);
+    exit_events.write(AppExit::Success);
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+//! # History Plugin
+//!
+//! Records every finished run in `run_history.json` under the user data directory,
+//! so scores survive the world reset even when nothing is sent to the leaderboard.
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::prelude::{
+    game_rng::GameSeed,
+    run_history::*,
+    run_stats::RunStats,
+    score::Score,
+    session_time::SessionTime,
+};
+use crate::storage::{data_path, load_json, save_json};
+
+pub(crate) fn plugin(app: &mut App) {
+    app
+        .insert_resource(load_json::<RunHistory>(&data_path(RUN_HISTORY_FILE)).unwrap_or_default())
+        .add_systems(OnEnter(GameState::GameOver), record_run_system)
+        .add_systems(OnEnter(GameState::Victory), record_run_system);
+}
+
+/// Adds the run that just ended to [RunHistory] and saves it.
+pub fn record_run_system(
+    mut history: ResMut<RunHistory>,
+    score: Res<Score>,
+    time: Res<SessionTime>,
+    seed: Res<GameSeed>,
+    stats: Res<RunStats>,
+    state: Res<State<GameState>>,
+) {
+    let date = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    history.record(RunRecord {
+        score: score.current,
+        duration: time.elapsed,
+        seed: seed.current,
+        date,
+        stats: *stats,
+        won: *state.get() == GameState::Victory,
+    });
+    save_json(&data_path(RUN_HISTORY_FILE), &*history);
+
+    if history.latest_is_best {
+        info!("HISTORY: new personal best {}", score.current);
+    }
+}
\ No newline at end of file
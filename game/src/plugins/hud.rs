@@ -6,6 +6,8 @@ use crate::prelude::notification_timer::*;
 use crate::prelude::score::*;
 use crate::prelude::session_time::*;
 use crate::prelude::player_ball::*;
+use crate::prelude::planet::TileState;
+use crate::prelude::planet_health::*;
 
 pub(crate) fn plugin(app: &mut App) {
     app
@@ -14,14 +16,17 @@ pub(crate) fn plugin(app: &mut App) {
             crate::plugins::vjoy::spawn_dash_button,
             spawn_health_bar,
             spawn_score_hud,
+            spawn_planet_status,
             spawn_factory_notification,
+            spawn_pause_button,
         ).chain())        
         .add_systems(Update, (
-            (score_event_handler, update_score_hud_system),
-            (track_session_time_system, update_time_hud_system),
+            (update_score_hud_system),
+            (update_time_hud_system),
+            (update_planet_status_system),
             (notification_lifecycle_system),
             (crate::plugins::vjoy::sync_dash_text_size)
-        ).run_if(in_state(GameState::Playing)).run_if(any_with_component::<PlayerBall>));
+        ).run_if(in_state(PauseState::Running)).run_if(any_with_component::<PlayerBall>));
 }
 
 fn notification_lifecycle_system(
@@ -73,15 +78,6 @@ fn spawn_factory_notification(mut commands: Commands) {
     });
 }
 
-fn score_event_handler(
-    mut messages: MessageReader<ScoreMessage>,
-    mut score: ResMut<Score>,
-) {
-    for msg in messages.read() {
-        score.current += msg.0;
-    }
-}
-
 fn spawn_score_hud(mut commands: Commands) {
     commands.spawn((
         ScoreHud, 
@@ -120,20 +116,12 @@ fn update_score_hud_system(
     score: Res<Score>,
     mut q_text: Query<&mut Text, With<ScoreHudText>>,
 ) {
-    if score.is_changed() {
-        if let Ok(mut text) = q_text.single_mut() {
-            text.0 = format!("SCORE: {}", score.current);
-        }
+    if score.is_changed()
+        && let Ok(mut text) = q_text.single_mut() {
+        text.0 = format!("SCORE: {}", score.current);
     }
 }
 
-fn track_session_time_system(
-    time: Res<Time>,
-    mut session_time: ResMut<SessionTime>,
-) {
-    session_time.elapsed += time.delta_secs();
-}
-
 fn update_time_hud_system(
     session_time: Res<SessionTime>,
     mut q_text: Query<&mut Text, With<TimeHudText>>,
@@ -143,6 +131,99 @@ fn update_time_hud_system(
     }
 }
 
+/// Top-left readout of how the fight for the planet is going.
+fn spawn_planet_status(mut commands: Commands) {
+    commands.spawn((
+        SessionUi,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::VMin(2.0),
+            left: Val::VMin(2.0),
+            padding: UiRect::all(Val::VMin(1.0)),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(2.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.4)),
+        BorderRadius::all(Val::Px(6.0)),
+        ZIndex(100),
+    ))
+    .with_children(|parent| {
+        for (state, color) in [
+            (TileState::Healthy, Color::srgb(0.3, 1.0, 0.3)),
+            (TileState::Wasteland, Color::srgb(0.8, 0.7, 0.5)),
+            (TileState::Polluted, Color::srgb(1.0, 0.3, 0.3)),
+        ] {
+            parent.spawn((
+                TileShareText(state),
+                Text::new(""),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(color),
+            ));
+        }
+
+        parent.spawn((
+            FactoryCountText,
+            Text::new(""),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn update_planet_status_system(
+    health: Res<PlanetHealth>,
+    mut q_shares: Query<(&mut Text, &TileShareText), Without<FactoryCountText>>,
+    mut q_factories: Query<&mut Text, With<FactoryCountText>>,
+    q_added: Query<(), Added<TileShareText>>,
+) {
+    if !health.is_changed() && q_added.is_empty() { return; }
+
+    for (mut text, share) in q_shares.iter_mut() {
+        let label = match share.0 {
+            TileState::Healthy => "HEALTHY",
+            TileState::Wasteland => "WASTELAND",
+            TileState::Polluted => "POLLUTED",
+        };
+        text.0 = format!("{}: {:.0}%", label, health.fraction(share.0) * 100.0);
+    }
+
+    if let Ok(mut text) = q_factories.single_mut() {
+        text.0 = format!("FACTORIES: {}", health.factories);
+    }
+}
+
+fn spawn_pause_button(mut commands: Commands) {
+    commands.spawn((
+        PauseButton,
+        SessionUi,
+        Button,
+        Interaction::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::VMin(2.0),
+            right: Val::VMin(2.0),
+            width: Val::VMin(8.0),
+            height: Val::VMin(8.0),
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+        BorderRadius::all(Val::Px(10.0)),
+        ZIndex(100),
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            Text::new("II"),
+            TextFont { font_size: 24.0, ..default() },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
 fn spawn_health_bar(mut commands: Commands) {
     commands.spawn((
         SessionUi,
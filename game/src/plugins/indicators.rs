@@ -0,0 +1,168 @@
+//! # Indicators Plugin
+//!
+//! Arrows on the edge of the screen pointing at the nearest factories and orbs that
+//! are out of view. An arrow follows the great circle from the player to its target,
+//! shows the distance along it, and grows more opaque as the target gets closer.
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::prelude::{
+    camera::BirdEyeCamera,
+    factory::AlienFactory,
+    orbs::EnergyOrb,
+    planet_settings::PlanetSettings,
+    player_ball::PlayerBall,
+    ui::*,
+};
+
+/// Arrows kept per kind of target.
+const FACTORY_ARROWS: usize = 3;
+const ORB_ARROWS: usize = 2;
+
+/// Gap between the arrows and the screen edge, in logical pixels.
+const EDGE_MARGIN: f32 = 48.0;
+
+/// Opacity of an arrow whose target is on the far side of the planet.
+const MIN_ALPHA: f32 = 0.2;
+
+pub(crate) fn plugin(app: &mut App) {
+    app
+        .add_systems(OnEnter(GameState::Playing), spawn_indicators)
+        .add_systems(Update, update_indicators_system
+            .run_if(in_state(PauseState::Running))
+            .run_if(any_with_component::<PlayerBall>));
+}
+
+fn spawn_indicators(mut commands: Commands) {
+    let slots = (0..FACTORY_ARROWS).map(|slot| (IndicatorTarget::Factory, slot))
+        .chain((0..ORB_ARROWS).map(|slot| (IndicatorTarget::Orb, slot)));
+
+    for (target, slot) in slots {
+        let color = indicator_color(target);
+        commands.spawn((
+            SessionUi,
+            OffscreenIndicator { target, slot },
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(EDGE_MARGIN),
+                height: Val::Px(EDGE_MARGIN),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Visibility::Hidden,
+            ZIndex(90),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                IndicatorArrow,
+                Text::new(">"),
+                TextFont { font_size: 28.0, ..default() },
+                TextColor(color),
+                UiTransform::default(),
+            ));
+            parent.spawn((
+                IndicatorLabel,
+                Text::new(""),
+                TextFont { font_size: 12.0, ..default() },
+                TextColor(color),
+            ));
+        });
+    }
+}
+
+fn indicator_color(target: IndicatorTarget) -> Color {
+    match target {
+        IndicatorTarget::Factory => Color::srgb(1.0, 0.2, 0.2),
+        IndicatorTarget::Orb => Color::srgb(0.2, 1.0, 0.4),
+    }
+}
+
+fn update_indicators_system(
+    settings: Res<PlanetSettings>,
+    q_player: Query<&GlobalTransform, With<PlayerBall>>,
+    q_camera: Query<(&Camera, &GlobalTransform), With<BirdEyeCamera>>,
+    q_factories: Query<&GlobalTransform, With<AlienFactory>>,
+    q_orbs: Query<&GlobalTransform, With<EnergyOrb>>,
+    mut q_indicators: Query<(&OffscreenIndicator, &mut Node, &mut Visibility, &Children)>,
+    mut q_arrows: Query<(&mut UiTransform, &mut TextColor), (With<IndicatorArrow>, Without<IndicatorLabel>)>,
+    mut q_labels: Query<(&mut Text, &mut TextColor), (With<IndicatorLabel>, Without<IndicatorArrow>)>,
+) {
+    let Ok(player) = q_player.single() else { return; };
+    let Ok((camera, camera_gtrans)) = q_camera.single() else { return; };
+    let Some(viewport) = camera.logical_viewport_size() else { return; };
+
+    let radius = settings.radius;
+    let player_dir = player.translation().normalize_or_zero();
+    let camera_pos = camera_gtrans.translation();
+
+    // Off-screen targets of each kind, nearest first, as (geodesic distance, direction)
+    let offscreen = |positions: Vec<Vec3>| -> Vec<(f32, Vec3)> {
+        let mut targets: Vec<(f32, Vec3)> = positions.into_iter()
+            .filter(|&pos| {
+                // Hidden behind the planet, or outside the frame
+                let beyond_horizon = pos.normalize_or_zero().dot(camera_pos) < radius;
+                let in_frame = camera.world_to_viewport(camera_gtrans, pos).ok()
+                    .is_some_and(|p| p.cmpge(Vec2::ZERO).all() && p.cmple(viewport).all());
+                beyond_horizon || !in_frame
+            })
+            .map(|pos| {
+                let dir = pos.normalize_or_zero();
+                let distance = player_dir.dot(dir).clamp(-1.0, 1.0).acos() * radius;
+                (distance, dir)
+            })
+            .collect();
+        targets.sort_by(|a, b| a.0.total_cmp(&b.0));
+        targets
+    };
+
+    let factories = offscreen(q_factories.iter().map(GlobalTransform::translation).collect());
+    let orbs = offscreen(q_orbs.iter().map(GlobalTransform::translation).collect());
+
+    let center = viewport * 0.5;
+    let half_extent = (center - Vec2::splat(EDGE_MARGIN)).max(Vec2::ONE);
+    let max_distance = std::f32::consts::PI * radius;
+
+    for (indicator, mut node, mut visibility, children) in q_indicators.iter_mut() {
+        let targets = match indicator.target {
+            IndicatorTarget::Factory => &factories,
+            IndicatorTarget::Orb => &orbs,
+        };
+        let Some(&(distance, target_dir)) = targets.get(indicator.slot) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        // First step along the great circle, seen on screen (y grows downwards)
+        let tangent = (target_dir - player_dir * player_dir.dot(target_dir)).normalize_or_zero();
+        let screen_dir = Vec2::new(tangent.dot(*camera_gtrans.right()), -tangent.dot(*camera_gtrans.up()));
+        let Some(screen_dir) = screen_dir.try_normalize() else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        // Push the arrow out from the centre until it meets the inset screen border
+        let reach = (half_extent / screen_dir.abs().max(Vec2::splat(f32::EPSILON))).min_element();
+        let pos = center + screen_dir * reach - Vec2::splat(EDGE_MARGIN * 0.5);
+        node.left = Val::Px(pos.x);
+        node.top = Val::Px(pos.y);
+        *visibility = Visibility::Inherited;
+
+        let closeness = 1.0 - (distance / max_distance).clamp(0.0, 1.0);
+        let alpha = MIN_ALPHA + (1.0 - MIN_ALPHA) * closeness;
+
+        for &child in children {
+            if let Ok((mut transform, mut color)) = q_arrows.get_mut(child) {
+                transform.rotation = Rot2::radians(screen_dir.y.atan2(screen_dir.x));
+                color.0 = color.0.with_alpha(alpha);
+            }
+            if let Ok((mut text, mut color)) = q_labels.get_mut(child) {
+                text.0 = format!("{:.0}m", distance);
+                color.0 = color.0.with_alpha(alpha);
+            }
+        }
+    }
+}
\ No newline at end of file
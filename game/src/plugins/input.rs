@@ -1 +1,183 @@
-#![allow(unused)]
+//! # Input Plugin
+//!
+//! Maps keyboard and gamepad onto the gameplay actions (move, dash, pause).
+//!
+//! Movement is written into [VjoyOutput] and dashing raises the [DashState] trigger,
+//! so the player systems don't care which device is in use. The on-screen joystick and
+//! dash button hide themselves while a physical device is driving the ball.
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::prelude::{
+    active_touch::ActiveTouch,
+    vjoy_base::VjoyBase,
+    vjoy_output::VjoyOutput,
+    dash::DashButton,
+    dash_state::DashState,
+    input_bindings::InputBindings,
+    player_input::*,
+};
+
+pub(crate) fn plugin(app: &mut App) {
+    app
+        .init_resource::<InputBindings>()
+        .init_resource::<PlayerInput>()
+        .register_type::<InputBindings>()
+        .register_type::<PlayerInput>()
+        .add_systems(Update, (
+            read_devices_system,
+            apply_actions_system.run_if(in_state(PauseState::Running)),
+            touch_controls_visibility_system,
+        ).chain().run_if(in_state(GameState::Playing)));
+}
+
+/// Collects this frame's actions and tracks which device was used last.
+fn read_devices_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    bindings: Res<InputBindings>,
+    q_gamepads: Query<&Gamepad>,
+    mut input: ResMut<PlayerInput>,
+) {
+    let axis = |codes: &[KeyCode]| if keys.any_pressed(codes.iter().copied()) { 1.0 } else { 0.0 };
+    let key_dir = Vec2::new(
+        axis(&bindings.move_right) - axis(&bindings.move_left),
+        axis(&bindings.move_up) - axis(&bindings.move_down),
+    );
+
+    let mut pad_dir = Vec2::ZERO;
+    let mut pad_dash = false;
+    let mut pad_pause = false;
+    let mut pad_used = false;
+
+    for gamepad in q_gamepads.iter() {
+        let stick = gamepad.left_stick();
+        let dir = if stick.length() > bindings.stick_deadzone { stick } else { gamepad.dpad() };
+        if dir != Vec2::ZERO { pad_dir = dir; }
+
+        pad_dash |= bindings.dash_buttons.iter().any(|b| gamepad.just_pressed(*b));
+        pad_pause |= bindings.pause_buttons.iter().any(|b| gamepad.just_pressed(*b));
+        pad_used |= dir != Vec2::ZERO || gamepad.get_just_pressed().next().is_some();
+    }
+
+    if mouse_buttons.get_just_pressed().next().is_some() || touches.any_just_pressed() {
+        input.device = InputDevice::Pointer;
+    } else if keys.get_just_pressed().next().is_some() {
+        input.device = InputDevice::Keyboard;
+    } else if pad_used {
+        input.device = InputDevice::Gamepad;
+    }
+
+    input.move_dir = match input.device {
+        InputDevice::Keyboard => key_dir.normalize_or_zero(),
+        InputDevice::Gamepad => pad_dir.clamp_length_max(1.0),
+        InputDevice::Pointer => Vec2::ZERO,
+    };
+    input.dash = keys.any_just_pressed(bindings.dash_keys.iter().copied()) || pad_dash;
+    input.pause = keys.any_just_pressed(bindings.pause_keys.iter().copied()) || pad_pause;
+}
+
+/// Feeds device actions into the same outputs the virtual joystick drives.
+fn apply_actions_system(
+    input: Res<PlayerInput>,
+    active_touch: Res<ActiveTouch>,
+    mut vjoy_output: ResMut<VjoyOutput>,
+    mut dash_state: ResMut<DashState>,
+) {
+    if input.device != InputDevice::Pointer && active_touch.id.is_none() {
+        vjoy_output.dir = input.move_dir;
+    }
+
+    if input.dash {
+        dash_state.requested = true;
+    }
+}
+
+/// Hides the joystick and dash button unless the player is on mouse or touch.
+fn touch_controls_visibility_system(
+    input: Res<PlayerInput>,
+    mut q_controls: Query<&mut Visibility, Or<(With<VjoyBase>, With<DashButton>)>>,
+) {
+    let target = if input.device == InputDevice::Pointer {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+
+    for mut vis in q_controls.iter_mut() {
+        vis.set_if_neq(target);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input_app() -> App {
+        let mut app = App::new();
+        app
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Touches>()
+            .init_resource::<InputBindings>()
+            .init_resource::<PlayerInput>()
+            .init_resource::<ActiveTouch>()
+            .init_resource::<VjoyOutput>()
+            .init_resource::<DashState>()
+            .add_systems(Update, (read_devices_system, apply_actions_system).chain());
+        app
+    }
+
+    #[test]
+    fn keys_drive_movement_dash_and_pause() {
+        let mut app = input_app();
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyW);
+        keys.press(KeyCode::ArrowRight);
+        keys.press(KeyCode::Space);
+        keys.press(KeyCode::Escape);
+        app.update();
+
+        let input = app.world().resource::<PlayerInput>();
+        assert_eq!(input.device, InputDevice::Keyboard);
+        assert!(input.move_dir.abs_diff_eq(Vec2::ONE.normalize(), 1e-6));
+        assert!(input.dash && input.pause);
+        assert_eq!(app.world().resource::<VjoyOutput>().dir, input.move_dir);
+        assert!(app.world().resource::<DashState>().requested);
+    }
+
+    #[test]
+    fn gamepad_drives_movement_dash_and_pause() {
+        let mut app = input_app();
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(GamepadAxis::LeftStickX, -1.0);
+        gamepad.digital_mut().press(GamepadButton::South);
+        gamepad.digital_mut().press(GamepadButton::Start);
+        app.world_mut().spawn(gamepad);
+        app.update();
+
+        let input = app.world().resource::<PlayerInput>();
+        assert_eq!(input.device, InputDevice::Gamepad);
+        assert_eq!(input.move_dir, Vec2::NEG_X);
+        assert!(input.dash && input.pause);
+        assert_eq!(app.world().resource::<VjoyOutput>().dir, Vec2::NEG_X);
+        assert!(app.world().resource::<DashState>().requested);
+    }
+
+    #[test]
+    fn stick_inside_the_deadzone_falls_back_to_the_dpad() {
+        let mut app = input_app();
+        let mut gamepad = Gamepad::default();
+        gamepad.analog_mut().set(GamepadAxis::LeftStickY, 0.1);
+        gamepad.digital_mut().press(GamepadButton::DPadUp);
+        gamepad.analog_mut().set(GamepadButton::DPadUp, 1.0);
+        app.world_mut().spawn(gamepad);
+        app.update();
+
+        let input = app.world().resource::<PlayerInput>();
+        assert_eq!(input.move_dir, Vec2::Y);
+        assert!(!input.dash && !input.pause);
+    }
+}
\ No newline at end of file
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::input::keyboard::*;
 use bevy::color::palettes::css::*;
+use bevy::window::WindowFocused;
 
 use crate::prelude::*;
 use crate::prelude::{vjoy_base::VjoyBase, dash::DashButton,};
@@ -11,33 +12,60 @@ use crate::resources::player_profile::*;
 use crate::resources::session_time::*;
 use crate::resources::reset_target::*;
 use crate::resources::leaderboard_channel::*;
-use crate::resources::firebase_config::*;
+use crate::resources::leaderboard_config::*;
+use crate::resources::leaderboard_backend::*;
+use crate::resources::score_queue::*;
+use crate::resources::leaderboard_status::*;
+use crate::resources::game_rng::*;
+use crate::resources::player_input::*;
+use crate::resources::run_history::*;
+use crate::resources::session_snapshot::*;
 use crate::components::ui::*;
+use crate::storage::{data_path, load_json, save_json};
 
 pub(crate) fn plugin(app: &mut App) {
     app
         .init_resource::<Leaderboard>()
         .init_resource::<LeaderboardChannel>()
-        .init_resource::<FirebaseConfig>()
-        .init_resource::<Leaderboard>()
+        .insert_resource(LeaderboardService::from_config(&LeaderboardConfig::resolve()))
+        .insert_resource(load_json::<ScoreQueue>(&data_path(SCORE_QUEUE_FILE)).unwrap_or_default())
+        .init_resource::<ScoreQueueChannel>()
+        .init_resource::<LeaderboardStatus>()
         .register_type::<Leaderboard>()
         .add_systems(OnEnter(GameState::MainMenu), (setup_main_menu, trigger_leaderboard_fetch))
-        .add_systems(Update, (ui_button_hover_system, (main_menu_system, leaderboard_scroll_system, username_typing_system, toggle_ime_system, leaderboard_receiver_system, update_leaderboard_ui_system).run_if(in_state(GameState::MainMenu))))
+        .add_systems(Update, (ui_button_hover_system, (main_menu_system, leaderboard_scroll_system, username_typing_system, toggle_ime_system, leaderboard_receiver_system, update_leaderboard_ui_system, leaderboard_retry_system, leaderboard_spinner_system).run_if(in_state(GameState::MainMenu))))
         .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu)
+        .add_systems(OnEnter(GameState::MainMenu), retry_score_queue)
         .add_systems(Update, (
-            (crate::plugins::vjoy::sync_dash_text_size)
-        ).run_if(in_state(GameState::Playing)))
-        .add_systems(OnEnter(GameState::GameOver), (setup_death_menu, cleanup_game_ui))
-        .add_systems(Update, death_menu_interaction_system.run_if(in_state(GameState::GameOver)))
-        .add_systems(OnExit(GameState::GameOver), cleanup_death_menu);
+            send_score_queue_system,
+            score_queue_receiver_system,
+            update_score_queue_ui_system,
+        ).chain().run_if(in_state(GameState::MainMenu).or(in_state(GameState::GameOver)).or(in_state(GameState::Victory))))
+        .add_systems(Update, crate::plugins::vjoy::sync_dash_text_size.run_if(in_state(GameState::Playing)))
+        .add_systems(Update, (pause_toggle_system, auto_pause_system).run_if(in_state(GameState::Playing)))
+        .add_systems(OnEnter(PauseState::Paused), setup_pause_menu)
+        .add_systems(Update, pause_menu_interaction_system.run_if(in_state(PauseState::Paused)))
+        .add_systems(OnExit(PauseState::Paused), cleanup_pause_menu)
+        .add_systems(OnEnter(GameState::GameOver), (setup_death_menu.after(crate::plugins::history::record_run_system), cleanup_game_ui))
+        .add_systems(OnEnter(GameState::Victory), (setup_death_menu.after(crate::plugins::history::record_run_system), cleanup_game_ui))
+        .add_systems(Update, death_menu_interaction_system.run_if(in_state(GameState::GameOver).or(in_state(GameState::Victory))))
+        .add_systems(OnExit(GameState::GameOver), cleanup_death_menu)
+        .add_systems(OnExit(GameState::Victory), cleanup_death_menu);
 }
 
+/// Results screen for a run that ended, lost (`GameOver`) or won (`Victory`).
 fn setup_death_menu(
     mut commands: Commands, 
     score: Res<Score>, 
     time: Res<SessionTime>,
-    profile: Res<PlayerProfile>
+    seed: Res<GameSeed>,
+    profile: Res<PlayerProfile>,
+    service: Res<LeaderboardService>,
+    history: Res<RunHistory>,
+    state: Res<State<GameState>>,
 ) {
+    let won = *state.get() == GameState::Victory;
+
     commands.spawn((
         DeathMenuRoot,
         Node {
@@ -55,24 +83,61 @@ fn setup_death_menu(
         ZIndex(200),
     ))
     .with_children(|parent| {
-        parent.spawn((
-            Text::new("GAME OVER!"),
-            TextFont { font_size: 80.0, ..default() },
-            TextColor(Color::srgb(1.0, 0.1, 0.1)),
-        ));
+        if won {
+            parent.spawn((
+                Text::new("PLANET RESTORED!"),
+                TextFont { font_size: 80.0, ..default() },
+                TextColor(Color::srgb(0.2, 1.0, 0.3)),
+            ));
+        } else {
+            parent.spawn((
+                Text::new("GAME OVER!"),
+                TextFont { font_size: 80.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.1, 0.1)),
+            ));
+        }
 
         parent.spawn((
             Text::new(format!("SCORE: {} | TIME: {}", score.current, time.format())),
             TextFont { font_size: 30.0, ..default() },
             TextColor(Color::WHITE),
+        ));
+
+        if won {
+            parent.spawn((
+                Text::new(format!("TIME BONUS: +{}", score.time_bonus)),
+                TextFont { font_size: 24.0, ..default() },
+                TextColor(Color::from(LIGHT_GREEN)),
+            ));
+        }
+
+        if history.latest_is_best {
+            parent.spawn((
+                Text::new("NEW PERSONAL BEST!"),
+                TextFont { font_size: 26.0, ..default() },
+                TextColor(Color::from(YELLOW)),
+            ));
+        } else {
+            parent.spawn((
+                Text::new(format!("BEST: {}", history.bests.score)),
+                TextFont { font_size: 20.0, ..default() },
+                TextColor(Color::from(GRAY)),
+            ));
+        }
+
+        parent.spawn((
+            Text::new(format!("SEED: {}", seed.current)),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::from(GRAY)),
             Node { margin: UiRect::bottom(Val::VMin(2.0)), ..default() },
         ));
 
-        if !profile.username.trim().is_empty() {
+        if service.is_enabled() && !profile.username.trim().is_empty() {
             spawn_menu_button(parent, SubmitScoreButton, "SUBMIT TO CLOUD", Color::srgb(0.0, 0.6, 0.8));
         }
 
         spawn_menu_button(parent, RestartButton, "RESTART", Color::srgb(0.2, 0.2, 0.2));
+        spawn_menu_button(parent, ReplaySeedButton, "REPLAY SEED", Color::srgb(0.2, 0.2, 0.2));
         spawn_menu_button(parent, MainMenuButton, "MAIN MENU", Color::srgb(0.1, 0.3, 0.1));
     });
 }
@@ -80,12 +145,14 @@ fn setup_death_menu(
 fn death_menu_interaction_system(
     mut next_state: ResMut<NextState<GameState>>,
     mut reset_target: ResMut<ResetTarget>,
-    mut leaderboard: ResMut<Leaderboard>,
+    mut seed: ResMut<GameSeed>,
+    mut queue: ResMut<ScoreQueue>,
     score: Res<Score>,
     time: Res<SessionTime>,
-    mut profile: ResMut<PlayerProfile>,
-    config: Res<FirebaseConfig>,
+    profile: Res<PlayerProfile>,
+    state: Res<State<GameState>>,
     q_restart: Query<&Interaction, (Changed<Interaction>, With<RestartButton>)>,
+    q_replay: Query<&Interaction, (Changed<Interaction>, With<ReplaySeedButton>)>,
     q_menu: Query<&Interaction, (Changed<Interaction>, With<MainMenuButton>)>,
     q_submit: Query<(Entity, &Interaction), (Changed<Interaction>, With<SubmitScoreButton>)>,
     mut commands: Commands,
@@ -95,36 +162,148 @@ fn death_menu_interaction_system(
         next_state.set(GameState::Resetting);
     }
 
+    if let Ok(Interaction::Pressed) = q_replay.single() {
+        seed.pinned = Some(seed.current);
+        reset_target.0 = GameState::Playing;
+        next_state.set(GameState::Resetting);
+    }
+
     if let Ok(Interaction::Pressed) = q_menu.single() {
         reset_target.0 = GameState::MainMenu;
         next_state.set(GameState::Resetting);
     }
 
     if let Ok((btn_entity, Interaction::Pressed)) = q_submit.single() {
-        let entry = FirebaseEntry {
+        let id = queue.push(LeaderboardEntry {
             name: profile.username.clone(),
             score: score.current,
             time: time.elapsed,
-        };
+            won: *state.get() == GameState::Victory,
+        });
+        save_json(&data_path(SCORE_QUEUE_FILE), &*queue);
+
+        // The button turns into a status line for this submission
+        if let Ok(mut entity_cmds) = commands.get_entity(btn_entity) {
+            entity_cmds
+                .remove::<(Button, SubmitScoreButton, Interaction)>()
+                .despawn_children()
+                .with_child((
+                    SubmissionStatusText { id },
+                    Text::new(SubmissionStatus::Pending.label()),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+        }
+    }
+}
+
+fn retry_score_queue(mut queue: ResMut<ScoreQueue>) {
+    queue.retry_now();
+}
+
+/// Hands every due submission to the backend. Results come back through [ScoreQueueChannel].
+fn send_score_queue_system(
+    time: Res<Time>,
+    service: Res<LeaderboardService>,
+    channel: Res<ScoreQueueChannel>,
+    mut queue: ResMut<ScoreQueue>,
+) {
+    let Some(backend) = &service.backend else { return; };
+    let now = time.elapsed_secs_f64();
 
-        let url = format!("{}leaderboard.json", config.url);
-        let json = serde_json::to_string(&entry).unwrap();
-        let request = ehttp::Request::post(url, json.into_bytes());
+    let is_due = |item: &QueuedSubmission| {
+        item.status != SubmissionStatus::Sent && !item.in_flight && item.retry_at <= now
+    };
+    if !queue.items.iter().any(is_due) { return; }
+
+    for item in queue.items.iter_mut().filter(|item| is_due(item)) {
+        item.in_flight = true;
+        let (id, tx) = (item.id, channel.tx.clone());
+        backend.submit(item.entry.clone(), Box::new(move |result| {
+            let _ = tx.send((id, result));
+        }));
+    }
+}
+
+fn score_queue_receiver_system(
+    time: Res<Time>,
+    channel: Res<ScoreQueueChannel>,
+    mut queue: ResMut<ScoreQueue>,
+    mut leaderboard: ResMut<Leaderboard>,
+) {
+    let results: Vec<(u64, SubmitResult)> = channel.rx.lock()
+        .map(|rx| rx.try_iter().collect())
+        .unwrap_or_default();
+    if results.is_empty() { return; }
+
+    let now = time.elapsed_secs_f64();
+    for (id, result) in results {
+        let Some(item) = queue.items.iter_mut().find(|item| item.id == id) else { continue; };
+        item.in_flight = false;
 
-        ehttp::fetch(request, |result| {
-            if let Ok(resp) = result {
-                if resp.status == 200 { println!("Score accepted by Firebase!"); }
+        match result {
+            Ok(()) => {
+                info!("LEADERBOARD: Score accepted!");
+                item.status = SubmissionStatus::Sent;
+                leaderboard.entries.push(item.entry.clone());
+                leaderboard.entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
             }
-        });
+            Err(e) => {
+                warn!("LEADERBOARD SUBMIT ERROR: {}", e);
+                item.status = SubmissionStatus::Failed;
+                item.attempts += 1;
+                item.retry_at = now + ScoreQueue::backoff_secs(item.attempts);
+            }
+        }
+    }
 
-        leaderboard.entries.push((profile.username.clone(), score.current, time.elapsed));
-        leaderboard.entries.sort_by(|a, b| b.1.cmp(&a.1));
-        
-        profile.username = String::new();
-        if let Ok(mut entity_cmds) = commands.get_entity(btn_entity) {
-            entity_cmds.despawn();
+    queue.prune_sent();
+    save_json(&data_path(SCORE_QUEUE_FILE), &*queue);
+}
+
+fn update_score_queue_ui_system(
+    mut commands: Commands,
+    queue: Res<ScoreQueue>,
+    mut q_status: Query<(&SubmissionStatusText, &mut Text)>,
+    q_list: Query<Entity, With<ScoreQueueList>>,
+) {
+    if !queue.is_changed() { return; }
+
+    for (status_text, mut text) in q_status.iter_mut() {
+        if let Some(item) = queue.get(status_text.id) {
+            text.0 = match item.status {
+                SubmissionStatus::Failed => "FAILED - WILL RETRY".to_string(),
+                status => status.label().to_string(),
+            };
         }
     }
+
+    let Ok(list_entity) = q_list.single() else { return; };
+    commands.entity(list_entity).despawn_children();
+
+    let unsent = queue.items.iter().any(|item| item.status != SubmissionStatus::Sent);
+    if !unsent { return; }
+
+    commands.entity(list_entity).with_children(|list| {
+        list.spawn((
+            Text::new("UPLOADS"),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::from(GRAY)),
+        ));
+
+        for item in queue.items.iter().rev() {
+            let color = match item.status {
+                SubmissionStatus::Pending => Color::from(YELLOW),
+                SubmissionStatus::Sent => Color::from(LIGHT_CYAN),
+                SubmissionStatus::Failed => Color::srgb(1.0, 0.3, 0.3),
+            };
+            list.spawn((
+                Text::new(format!("{} {} - {}", item.entry.name, item.entry.score, item.status.label())),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(color),
+            ));
+        }
+    });
 }
 
 fn cleanup_death_menu(mut commands: Commands, q_root: Query<Entity, With<DeathMenuRoot>>) {
@@ -134,7 +313,108 @@ fn cleanup_death_menu(mut commands: Commands, q_root: Query<Entity, With<DeathMe
     }
 }
 
-fn setup_main_menu(mut commands: Commands, leaderboard: Res<Leaderboard>) {
+fn pause_toggle_system(
+    input: Res<PlayerInput>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+    q_pause: Query<&Interaction, (Changed<Interaction>, With<PauseButton>)>,
+) {
+    let button_pressed = matches!(q_pause.single(), Ok(Interaction::Pressed));
+    if !input.pause && !button_pressed { return; }
+
+    next_pause.set(match pause_state.get() {
+        PauseState::Running => PauseState::Paused,
+        PauseState::Paused => PauseState::Running,
+    });
+}
+
+fn auto_pause_system(
+    mut focus_msgs: MessageReader<WindowFocused>,
+    pause_state: Res<State<PauseState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    let lost_focus = focus_msgs.read().any(|msg| !msg.focused);
+
+    if lost_focus && *pause_state.get() == PauseState::Running {
+        next_pause.set(PauseState::Paused);
+    }
+}
+
+fn setup_pause_menu(mut commands: Commands, score: Res<Score>, time: Res<SessionTime>) {
+    commands.spawn((
+        PauseMenuRoot,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::VMin(2.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        ZIndex(200),
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            Text::new("PAUSED"),
+            TextFont { font_size: 80.0, ..default() },
+            TextColor(Color::srgb(0.0, 1.0, 0.5)),
+        ));
+
+        parent.spawn((
+            Text::new(format!("SCORE: {} | TIME: {}", score.current, time.format())),
+            TextFont { font_size: 30.0, ..default() },
+            TextColor(Color::WHITE),
+            Node { margin: UiRect::bottom(Val::VMin(2.0)), ..default() },
+        ));
+
+        spawn_menu_button(parent, ResumeButton, "RESUME", Color::srgb(0.0, 0.6, 0.8));
+        spawn_menu_button(parent, RestartButton, "RESTART", Color::srgb(0.2, 0.2, 0.2));
+        spawn_menu_button(parent, MainMenuButton, "MAIN MENU", Color::srgb(0.1, 0.3, 0.1));
+    });
+}
+
+fn pause_menu_interaction_system(
+    mut next_state: ResMut<NextState<GameState>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+    mut reset_target: ResMut<ResetTarget>,
+    q_resume: Query<&Interaction, (Changed<Interaction>, With<ResumeButton>)>,
+    q_restart: Query<&Interaction, (Changed<Interaction>, With<RestartButton>)>,
+    q_menu: Query<&Interaction, (Changed<Interaction>, With<MainMenuButton>)>,
+) {
+    if let Ok(Interaction::Pressed) = q_resume.single() {
+        next_pause.set(PauseState::Running);
+    }
+
+    if let Ok(Interaction::Pressed) = q_restart.single() {
+        reset_target.0 = GameState::Playing;
+        next_state.set(GameState::Resetting);
+    }
+
+    if let Ok(Interaction::Pressed) = q_menu.single() {
+        reset_target.0 = GameState::MainMenu;
+        next_state.set(GameState::Resetting);
+    }
+}
+
+fn cleanup_pause_menu(mut commands: Commands, q_root: Query<Entity, With<PauseMenuRoot>>) {
+    if let Ok(entity) = q_root.single() {
+        commands.entity(entity).despawn_children();
+        commands.entity(entity).despawn();
+    }
+}
+
+fn setup_main_menu(
+    mut commands: Commands,
+    leaderboard: Res<Leaderboard>,
+    service: Res<LeaderboardService>,
+    profile: Res<PlayerProfile>,
+    history: Res<RunHistory>,
+    saved: Res<SavedSession>,
+) {
     commands.spawn((
         MainMenuRoot, 
         SessionUi,
@@ -178,15 +458,32 @@ fn setup_main_menu(mut commands: Commands, leaderboard: Res<Leaderboard>) {
             .with_children(|p| {
                 p.spawn((
                     UsernameInputText,
-                    Text::new("TYPE NAME..."),
+                    Text::new(if profile.username.is_empty() { "TYPE NAME...".to_string() } else { profile.username.clone() }),
                     TextFont { font_size: 20.0, ..default() },
                     TextColor(Color::from(GRAY))
                 ));
             });
 
+            if saved.snapshot.is_some() {
+                spawn_menu_button(menu, ContinueButton, "CONTINUE", Color::srgb(0.0, 0.6, 0.3));
+            }
             spawn_menu_button(menu, StartButton, "START MISSION", Color::srgb(0.2, 0.2, 0.2));
-            spawn_menu_button(menu, ShowLeaderboardButton, "LEADERBOARD", Color::srgb(0.2, 0.2, 0.4));
+            if service.is_enabled() {
+                spawn_menu_button(menu, ShowLeaderboardButton, "LEADERBOARD", Color::srgb(0.2, 0.2, 0.4));
+            }
+            spawn_menu_button(menu, ShowMyRunsButton, "MY RUNS", Color::srgb(0.2, 0.3, 0.2));
             spawn_menu_button(menu, ExitButton, "EXIT", Color::srgb(0.2, 0.1, 0.1));
+
+            menu.spawn((
+                ScoreQueueList,
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+            ));
         });
 
         parent.spawn((
@@ -247,8 +544,8 @@ fn setup_main_menu(mut commands: Commands, leaderboard: Res<Leaderboard>) {
                         },
                     ))
                     .with_children(|list| {
-                        for (name, score, time) in &leaderboard.entries {
-                            spawn_leaderboard_row(list, name, *score, *time);
+                        for entry in &leaderboard.entries {
+                            spawn_leaderboard_row(list, &entry.name, entry.score, entry.time, entry.won);
                         }
                     });
                 });
@@ -256,10 +553,91 @@ fn setup_main_menu(mut commands: Commands, leaderboard: Res<Leaderboard>) {
                 spawn_menu_button(box_node, CloseLeaderboardButton, "BACK", Color::srgb(0.3, 0.3, 0.3));
             });
         });
+
+        spawn_my_runs_panel(parent, &history);
+    });
+}
+
+/// Overlay listing personal bests and the most recent local runs.
+fn spawn_my_runs_panel(parent: &mut ChildSpawnerCommands, history: &RunHistory) {
+    parent.spawn((
+        MyRunsPanel,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.98)),
+        Visibility::Hidden,
+    ))
+    .with_children(|overlay| {
+        overlay.spawn((
+            Node {
+                width: Val::Px(600.0),
+                padding: UiRect::all(Val::Px(30.0)),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+            BorderRadius::all(Val::Px(15.0))
+        ))
+        .with_children(|box_node| {
+            box_node.spawn((
+                Text::new("PERSONAL BESTS"),
+                TextFont { font_size: 32.0, ..default() },
+                TextColor(Color::from(YELLOW)),
+            ));
+
+            let bests = &history.bests;
+            let survived = SessionTime { elapsed: bests.duration }.format();
+            box_node.spawn((
+                Text::new(format!(
+                    "SCORE {}  |  SURVIVED {}\nTILES {}  |  FACTORIES {}",
+                    bests.score, survived, bests.tiles_restored, bests.factories_destroyed,
+                )),
+                TextFont { font_size: 20.0, ..default() },
+                TextColor(Color::from(LIGHT_CYAN)),
+                TextLayout::new_with_justify(Justify::Center),
+                Node { margin: UiRect::bottom(Val::Px(20.0)), ..default() },
+            ));
+
+            box_node.spawn((
+                Text::new("RECENT RUNS"),
+                TextFont { font_size: 24.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+
+            if history.runs.is_empty() {
+                box_node.spawn((
+                    Text::new("NO RUNS YET"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::from(GRAY)),
+                ));
+            }
+
+            for run in history.recent(8) {
+                let label = format!("{}  SEED {}", run.date_label(), run.seed);
+                spawn_leaderboard_row(box_node, &label, run.score, run.duration, run.won);
+            }
+
+            spawn_menu_button(box_node, CloseMyRunsButton, "BACK", Color::srgb(0.3, 0.3, 0.3));
+        });
     });
 }
 
-fn spawn_leaderboard_row(parent: &mut ChildSpawnerCommands, name: &str, score: usize, time: f32) {
+/// Wins are labelled, since a won run also stops the clock early.
+fn entry_label(name: &str, won: bool) -> String {
+    if won { format!("{} (WIN)", name) } else { name.to_string() }
+}
+
+fn spawn_leaderboard_row(parent: &mut ChildSpawnerCommands, name: &str, score: usize, time: f32, won: bool) {
     parent.spawn((
         Node {
             display: Display::Flex,
@@ -272,7 +650,7 @@ fn spawn_leaderboard_row(parent: &mut ChildSpawnerCommands, name: &str, score: u
         BorderColor::from(BLACK),
     ))
     .with_children(|row| {
-        row.spawn((Text::new(name.to_string()), TextColor(Color::WHITE)));
+        row.spawn((Text::new(entry_label(name, won)), TextColor(Color::WHITE)));
         
         let mins = (time / 60.0) as u32; 
         let secs = (time % 60.0) as u32;
@@ -285,26 +663,42 @@ fn spawn_leaderboard_row(parent: &mut ChildSpawnerCommands, name: &str, score: u
 
 fn main_menu_system(
     mut next_state: ResMut<NextState<GameState>>,
+    mut saved: ResMut<SavedSession>,
     q_start: Query<&Interaction, (Changed<Interaction>, With<StartButton>)>,
+    q_continue: Query<&Interaction, (Changed<Interaction>, With<ContinueButton>)>,
     q_exit: Query<&Interaction, (Changed<Interaction>, With<ExitButton>)>,
     q_lb_show: Query<&Interaction, (Changed<Interaction>, With<ShowLeaderboardButton>)>,
     q_lb_close: Query<&Interaction, (Changed<Interaction>, With<CloseLeaderboardButton>)>,
+    q_runs_show: Query<&Interaction, (Changed<Interaction>, With<ShowMyRunsButton>)>,
+    q_runs_close: Query<&Interaction, (Changed<Interaction>, With<CloseMyRunsButton>)>,
     mut q_panel: Query<&mut Visibility, With<LeaderboardPanel>>,
+    mut q_runs_panel: Query<&mut Visibility, (With<MyRunsPanel>, Without<LeaderboardPanel>)>,
     mut exit_events: MessageWriter<AppExit>,
 ) {
     let Ok(panel_visibility) = q_panel.single() else { return; };
+    let Ok(runs_visibility) = q_runs_panel.single() else { return; };
     
     let is_leaderboard_open = *panel_visibility != Visibility::Hidden;
+    let is_runs_open = *runs_visibility != Visibility::Hidden;
 
-    if !is_leaderboard_open {
+    if !is_leaderboard_open && !is_runs_open {
         if let Ok(Interaction::Pressed) = q_start.single() {
             next_state.set(GameState::Playing);
         }
 
-        if let Ok(Interaction::Pressed) = q_lb_show.single() {
-            if let Ok(mut vis) = q_panel.single_mut() {
-                *vis = Visibility::Inherited;
-            }
+        if let Ok(Interaction::Pressed) = q_continue.single() {
+            saved.resume = true;
+            next_state.set(GameState::Playing);
+        }
+
+        if let Ok(Interaction::Pressed) = q_lb_show.single()
+            && let Ok(mut vis) = q_panel.single_mut() {
+            *vis = Visibility::Inherited;
+        }
+
+        if let Ok(Interaction::Pressed) = q_runs_show.single()
+            && let Ok(mut vis) = q_runs_panel.single_mut() {
+            *vis = Visibility::Inherited;
         }
 
         if let Ok(Interaction::Pressed) = q_exit.single() {
@@ -312,12 +706,16 @@ fn main_menu_system(
         }
     }
 
-    if is_leaderboard_open {
-        if let Ok(Interaction::Pressed) = q_lb_close.single() {
-            if let Ok(mut vis) = q_panel.single_mut() {
-                *vis = Visibility::Hidden;
-            }
-        }
+    if is_runs_open
+        && let Ok(Interaction::Pressed) = q_runs_close.single()
+        && let Ok(mut vis) = q_runs_panel.single_mut() {
+        *vis = Visibility::Hidden;
+    }
+
+    if is_leaderboard_open
+        && let Ok(Interaction::Pressed) = q_lb_close.single()
+        && let Ok(mut vis) = q_panel.single_mut() {
+        *vis = Visibility::Hidden;
     }
 }
 
@@ -460,14 +858,13 @@ fn username_typing_system(
         }
     }
 
-    if changed {
-        if let Ok(mut text) = q_text.single_mut() {
-            text.0 = if profile.username.is_empty() {
-                "TYPE NAME...".to_string()
-            } else {
-                profile.username.clone()
-            };
-        }
+    if changed
+        && let Ok(mut text) = q_text.single_mut() {
+        text.0 = if profile.username.is_empty() {
+            "TYPE NAME...".to_string()
+        } else {
+            profile.username.clone()
+        };
     }
 }
 
@@ -486,52 +883,51 @@ fn toggle_ime_system(
     }
 }
 
-fn trigger_leaderboard_fetch(config: Res<FirebaseConfig>, channel: Res<LeaderboardChannel>) {
+fn trigger_leaderboard_fetch(
+    service: Res<LeaderboardService>,
+    channel: Res<LeaderboardChannel>,
+    mut status: ResMut<LeaderboardStatus>,
+) {
+    start_leaderboard_fetch(&service, &channel, &mut status);
+}
+
+fn start_leaderboard_fetch(service: &LeaderboardService, channel: &LeaderboardChannel, status: &mut LeaderboardStatus) {
+    let Some(backend) = &service.backend else { return; };
     let tx = channel.tx.clone();
-    let url = format!("{}leaderboard.json?orderBy=\"score\"&limitToLast=10", config.url);
-    
-    let request = ehttp::Request::get(url);
+    *status = LeaderboardStatus::Loading;
 
-    ehttp::fetch(request, move |result| {
-        match result {
-            Ok(response) => {
-                if response.status == 200 {
-                    let raw_json: serde_json::Value = serde_json::from_slice(&response.bytes).unwrap_or_default();
-                    
-                    if raw_json.is_null() {
-                        println!("FIREBASE: Database is empty.");
-                        return;
-                    }
+    backend.fetch_top(10, Box::new(move |result| {
+        let _ = tx.send(result);
+    }));
+}
 
-                    if let Some(obj) = raw_json.as_object() {
-                        let mut entries = Vec::new();
-                        for (_id, val) in obj {
-                            if let (Some(name), Some(score), Some(time)) = (
-                                val.get("name").and_then(|v| v.as_str()),
-                                val.get("score").and_then(|v| v.as_u64()),
-                                val.get("time").and_then(|v| v.as_f64()),
-                            ) {
-                                entries.push((name.to_string(), score as usize, time as f32));
-                            }
-                        }
+fn leaderboard_retry_system(
+    service: Res<LeaderboardService>,
+    channel: Res<LeaderboardChannel>,
+    mut status: ResMut<LeaderboardStatus>,
+    q_retry: Query<&Interaction, (Changed<Interaction>, With<RetryLeaderboardButton>)>,
+) {
+    if let Ok(Interaction::Pressed) = q_retry.single() {
+        start_leaderboard_fetch(&service, &channel, &mut status);
+    }
+}
 
-                        entries.sort_by(|a, b| b.1.cmp(&a.1));
-                        
-                        let _ = tx.send(entries);
-                        println!("FIREBASE: Successfully parsed {} entries", obj.len());
-                    }
-                } else {
-                    println!("FIREBASE FETCH ERROR: Status {}", response.status);
-                }
-            }
-            Err(e) => println!("NETWORK ERROR: {}", e),
-        }
-    });
+fn leaderboard_spinner_system(
+    time: Res<Time>,
+    mut q_spinner: Query<&mut Text, With<LeaderboardSpinner>>,
+) {
+    const FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+    let frame = FRAMES[(time.elapsed_secs() * 8.0) as usize % FRAMES.len()];
+
+    for mut text in q_spinner.iter_mut() {
+        text.0 = format!("LOADING {}", frame);
+    }
 }
 
 fn leaderboard_receiver_system(
     channel: Res<LeaderboardChannel>,
     mut leaderboard: ResMut<Leaderboard>,
+    mut status: ResMut<LeaderboardStatus>,
 ) {
     let new_data = if let Ok(rx) = channel.rx.lock() {
         rx.try_recv().ok()
@@ -539,23 +935,55 @@ fn leaderboard_receiver_system(
         None
     };
 
-    if let Some(new_entries) = new_data {
-        leaderboard.entries = new_entries;
-        println!("LOCAL: Leaderboard resource updated with fresh data!");
+    match new_data {
+        Some(Ok(new_entries)) => {
+            info!("LEADERBOARD: Loaded {} entries", new_entries.len());
+            *status = if new_entries.is_empty() { LeaderboardStatus::Empty } else { LeaderboardStatus::Loaded };
+            leaderboard.entries = new_entries;
+        }
+        Some(Err(e)) => {
+            warn!("LEADERBOARD FETCH ERROR: {}", e);
+            *status = LeaderboardStatus::Error(e);
+        }
+        None => {}
     }
 }
 
 fn update_leaderboard_ui_system(
     mut commands: Commands,
     leaderboard: Res<Leaderboard>,
+    status: Res<LeaderboardStatus>,
     q_container: Query<Entity, With<LeaderboardContentArea>>,
 ) {
-    if !leaderboard.is_changed() { return; }
+    if !leaderboard.is_changed() && !status.is_changed() { return; }
     let Ok(container_entity) = q_container.single() else { return; };
 
     commands.entity(container_entity).despawn_children();
 
     commands.entity(container_entity).with_children(|parent| {
+        match &*status {
+            LeaderboardStatus::Loading => {
+                parent.spawn((
+                    LeaderboardSpinner,
+                    Text::new("LOADING"),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::from(bevy::color::palettes::css::GRAY)),
+                ));
+                return;
+            }
+            LeaderboardStatus::Error(e) => {
+                parent.spawn((
+                    Text::new(e.summary()),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(Color::srgb(1.0, 0.3, 0.3)),
+                    Node { margin: UiRect::bottom(Val::Px(15.0)), ..default() },
+                ));
+                spawn_menu_button(parent, RetryLeaderboardButton, "RETRY", Color::srgb(0.2, 0.2, 0.4));
+                return;
+            }
+            _ => {}
+        }
+
         if leaderboard.entries.is_empty() {
             parent.spawn((
                 Text::new("NO SCORES YET..."),
@@ -564,7 +992,7 @@ fn update_leaderboard_ui_system(
             ));
         }
 
-        for (name, score, time_val) in &leaderboard.entries {
+        for entry in &leaderboard.entries {
             parent.spawn((
                 Node {
                     display: Display::Flex,
@@ -578,15 +1006,15 @@ fn update_leaderboard_ui_system(
             ))
             .with_children(|row| {
                 row.spawn((
-                    Text::new(name.clone()), 
+                    Text::new(entry_label(&entry.name, entry.won)), 
                     TextFont { font_size: 18.0, ..default() },
                     TextColor(Color::WHITE)
                 ));
                 
-                let mins = (*time_val / 60.0) as u32;
-                let secs = (*time_val % 60.0) as u32;
+                let mins = (entry.time / 60.0) as u32;
+                let secs = (entry.time % 60.0) as u32;
                 row.spawn((
-                    Text::new(format!("{} [{:02}:{:02}]", score, mins, secs)), 
+                    Text::new(format!("{} [{:02}:{:02}]", entry.score, mins, secs)), 
                     TextFont { font_size: 18.0, ..default() },
                     TextColor(Color::from(bevy::color::palettes::css::LIGHT_CYAN))
                 ));
@@ -0,0 +1,219 @@
+//! # Minimap Plugin
+//!
+//! Two small globes in the corner of the screen: the hemisphere under the player and
+//! the one on the far side, so factories deployed out of sight still show up.
+//!
+//! Each globe is an orthographic camera parented to the `PlanetPivot` that renders the
+//! planet chunks (with their live tile states) into an image shown by the UI. Factories,
+//! machines and orbs get a coloured dot on [MINIMAP_LAYER], which only these cameras see.
+
+use bevy::prelude::*;
+use bevy::camera::{RenderTarget, ScalingMode, visibility::RenderLayers};
+use bevy::render::render_resource::TextureFormat;
+
+use crate::prelude::*;
+use crate::prelude::{
+    factory::AlienFactory,
+    machine::AlienMachine,
+    minimap_camera::*,
+    orbs::EnergyOrb,
+    planet_pivot::PlanetPivot,
+    planet_settings::PlanetSettings,
+    ui::SessionUi,
+};
+
+/// Side of the near-side image in pixels. The far side is rendered at half that.
+const MAP_RESOLUTION: u32 = 192;
+
+/// How far above the surface the minimap cameras float.
+const CAMERA_ALTITUDE: f32 = 500.0;
+
+pub(crate) fn plugin(app: &mut App) {
+    app
+        .add_systems(Startup, spawn_minimap_cameras.after(crate::plugins::game::setup_planet))
+        .add_systems(OnEnter(GameState::Playing), spawn_minimap_ui)
+        .add_systems(OnEnter(GameState::Playing), set_minimap_active::<true>)
+        .add_systems(OnExit(GameState::Playing), set_minimap_active::<false>)
+        .add_systems(Update, (
+            attach_minimap_markers,
+            sync_minimap_cameras,
+        ).run_if(in_state(GameState::Playing)));
+}
+
+/// Images the minimap cameras render into.
+#[derive(Resource)]
+struct MinimapImages {
+    near: Handle<Image>,
+    far: Handle<Image>,
+}
+
+/// Shared marker meshes and materials, one material per kind of entity.
+#[derive(Resource)]
+struct MarkerAssets {
+    mesh: Handle<Mesh>,
+    factory: Handle<StandardMaterial>,
+    machine: Handle<StandardMaterial>,
+    orb: Handle<StandardMaterial>,
+}
+
+fn spawn_minimap_cameras(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    settings: Res<PlanetSettings>,
+    q_pivot: Query<Entity, With<PlanetPivot>>,
+) {
+    let Ok(pivot) = q_pivot.single() else { return; };
+
+    let near = images.add(Image::new_target_texture(MAP_RESOLUTION, MAP_RESOLUTION, TextureFormat::Bgra8UnormSrgb));
+    let far = images.add(Image::new_target_texture(MAP_RESOLUTION / 2, MAP_RESOLUTION / 2, TextureFormat::Bgra8UnormSrgb));
+
+    for (far_side, image) in [(false, near.clone()), (true, far.clone())] {
+        let camera = commands.spawn((
+            MinimapCamera { far_side },
+            Camera3d::default(),
+            Camera {
+                target: RenderTarget::Image(image.into()),
+                clear_color: ClearColorConfig::Custom(Color::NONE),
+                order: -1,
+                is_active: false,
+                ..default()
+            },
+            Projection::Orthographic(OrthographicProjection::default_3d()),
+            // The sun only lights one side, the far globe has to be readable too
+            AmbientLight { brightness: 1500.0, ..default() },
+            RenderLayers::layer(MINIMAP_LAYER),
+            minimap_camera_transform(far_side, settings.radius),
+        )).id();
+        commands.entity(pivot).add_child(camera);
+    }
+
+    let unlit = |color: Color| StandardMaterial { base_color: color, unlit: true, ..default() };
+    commands.insert_resource(MarkerAssets {
+        mesh: meshes.add(Sphere::new(1.0).mesh().ico(1).unwrap()),
+        factory: materials.add(unlit(Color::srgb(1.0, 0.1, 0.1))),
+        machine: materials.add(unlit(Color::srgb(1.0, 0.6, 0.0))),
+        orb: materials.add(unlit(Color::srgb(0.2, 1.0, 0.4))),
+    });
+    commands.insert_resource(MinimapImages { near, far });
+}
+
+/// Straight above the player, or straight below the planet for the far side, looking
+/// at the centre with the same "up" as the `BirdEyeCamera`.
+fn minimap_camera_transform(far_side: bool, radius: f32) -> Transform {
+    let height = if far_side { -(radius + CAMERA_ALTITUDE) } else { radius + CAMERA_ALTITUDE };
+    Transform::from_xyz(0.0, height, 0.0).looking_at(Vec3::ZERO, -Vec3::Z)
+}
+
+fn set_minimap_active<const ACTIVE: bool>(mut q_cameras: Query<&mut Camera, With<MinimapCamera>>) {
+    for mut camera in q_cameras.iter_mut() {
+        camera.is_active = ACTIVE;
+    }
+}
+
+/// Keeps the globes framed when the planet radius is tuned at runtime.
+fn sync_minimap_cameras(
+    settings: Res<PlanetSettings>,
+    mut q_cameras: Query<(&MinimapCamera, &mut Transform, &mut Projection)>,
+    mut initialized: Local<bool>,
+) {
+    if *initialized && !settings.is_changed() { return; }
+    *initialized = true;
+
+    let size = settings.radius * 2.2;
+    for (minimap, mut transform, mut projection) in q_cameras.iter_mut() {
+        *transform = minimap_camera_transform(minimap.far_side, settings.radius);
+        *projection = Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::Fixed { width: size, height: size },
+            far: (settings.radius + CAMERA_ALTITUDE) * 2.0,
+            ..OrthographicProjection::default_3d()
+        });
+    }
+}
+
+fn attach_minimap_markers(
+    mut commands: Commands,
+    assets: Option<Res<MarkerAssets>>,
+    q_factories: Query<Entity, Added<AlienFactory>>,
+    q_machines: Query<Entity, Added<AlienMachine>>,
+    q_orbs: Query<Entity, Added<EnergyOrb>>,
+) {
+    let Some(assets) = assets else { return; };
+
+    let kinds = [
+        (q_factories.iter().collect::<Vec<_>>(), &assets.factory, 9.0),
+        (q_machines.iter().collect(), &assets.machine, 6.0),
+        (q_orbs.iter().collect(), &assets.orb, 6.0),
+    ];
+    for (entities, material, size) in kinds {
+        for entity in entities {
+            commands.entity(entity).with_child((
+                MinimapMarker,
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_scale(Vec3::splat(size)),
+                RenderLayers::layer(MINIMAP_LAYER),
+            ));
+        }
+    }
+}
+
+fn spawn_minimap_ui(mut commands: Commands, images: Option<Res<MinimapImages>>) {
+    let Some(images) = images else { return; };
+
+    commands.spawn((
+        SessionUi,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::VMin(12.0),
+            right: Val::VMin(2.0),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::VMin(1.0),
+            ..default()
+        },
+        ZIndex(100),
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            ImageNode::new(images.near.clone()),
+            Node {
+                width: Val::VMin(22.0),
+                height: Val::VMin(22.0),
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        ))
+        .with_child((
+            // The player is always in the middle of the near globe
+            Node {
+                width: Val::VMin(1.5),
+                height: Val::VMin(1.5),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::WHITE),
+            BorderColor::from(Color::BLACK),
+            BorderRadius::MAX,
+        ));
+
+        parent.spawn((
+            Text::new("FAR SIDE"),
+            TextFont { font_size: 12.0, ..default() },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        ));
+
+        parent.spawn((
+            ImageNode::new(images.far.clone()),
+            Node {
+                width: Val::VMin(14.0),
+                height: Val::VMin(14.0),
+                ..default()
+            },
+        ));
+    });
+}
\ No newline at end of file
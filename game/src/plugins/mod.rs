@@ -1,9 +1,18 @@
+#[cfg(feature = "dev")]
 pub(super) mod debug;
 pub(super) mod game;
 pub(super) mod input;
 pub(super) mod defaults;
+pub(super) mod headless;
 pub(super) mod vjoy;
 pub(super) mod menu;
 pub(super) mod hud;
 pub(super) mod player;
-pub(super) mod enemies;
\ No newline at end of file
+pub(super) mod enemies;
+pub(super) mod settings;
+pub(super) mod history;
+pub(super) mod snapshot;
+pub(super) mod balance;
+pub(super) mod minimap;
+pub(super) mod indicators;
+pub(super) mod navigation;
\ No newline at end of file
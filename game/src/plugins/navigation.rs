@@ -0,0 +1,64 @@
+//! # Navigation Plugin
+//!
+//! Keeps one [FlowField] toward the player over the planet's tile graph, shared by
+//! every chasing machine. Tile costs come from `EnemySettings::path_costs`, so machines
+//! favour polluted ground and go around restored patches.
+//!
+//! The field is rebuilt when the player reaches another tile or tiles change state,
+//! at most once every `flow_field_refresh_secs`.
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::prelude::{
+    enemy_settings::EnemySettings,
+    flow_field::FlowField,
+    planet::*,
+    planet_settings::PlanetSettings,
+    player_ball::PlayerBall,
+    tile_changes::TileStateChanged,
+};
+
+pub(crate) fn plugin(app: &mut App) {
+    app
+        .init_resource::<FlowField>()
+        .add_systems(OnEnter(GameState::Playing), clear_flow_field)
+        .add_systems(Update, update_flow_field_system
+            .before(crate::plugins::enemies::alien_ai_system)
+            .run_if(in_state(PauseState::Running))
+            .run_if(any_with_component::<PlayerBall>));
+}
+
+fn clear_flow_field(mut field: ResMut<FlowField>) {
+    *field = FlowField::default();
+}
+
+fn update_flow_field_system(
+    time: Res<Time>,
+    planet_settings: Res<PlanetSettings>,
+    enemy_settings: Res<EnemySettings>,
+    q_player: Query<&GlobalTransform, With<PlayerBall>>,
+    q_planet: Query<(&Transform, &PlanetTiles), With<Planet>>,
+    mut changes: MessageReader<TileStateChanged>,
+    mut field: ResMut<FlowField>,
+    mut tiles_changed: Local<bool>,
+    mut since_rebuild: Local<f32>,
+) {
+    *since_rebuild += time.delta_secs();
+    if changes.read().count() > 0 { *tiles_changed = true; }
+
+    let Ok(player_gtrans) = q_player.single() else { return; };
+    let Ok((planet_trans, tiles)) = q_planet.single() else { return; };
+
+    let player_dir = (player_gtrans.translation() - planet_trans.translation) / planet_settings.radius;
+    let Some(goal) = tiles.nearest(player_dir.normalize()) else { return; };
+
+    let stale = field.tile_count() != tiles.len();
+    if !stale && field.goal() == Some(goal) && !*tiles_changed { return; }
+    if !stale && *since_rebuild < enemy_settings.flow_field_refresh_secs { return; }
+
+    let costs = enemy_settings.path_costs;
+    *field = FlowField::build(tiles, goal, |state| costs.get(state));
+    *tiles_changed = false;
+    *since_rebuild = 0.0;
+}
\ No newline at end of file
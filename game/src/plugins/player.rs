@@ -8,11 +8,13 @@ use crate::prelude::player_ball::*;
 use crate::prelude::planet_pivot::*;
 use crate::prelude::camera::*;
 use crate::prelude::machine::*;
+use crate::prelude::enemy_settings::*;
 use crate::prelude::factory::*;
 use crate::prelude::dash_settings::*;
 use crate::prelude::dash_state::*;
 use crate::prelude::ui::*;
 use crate::prelude::score::*;
+use crate::prelude::run_stats::*;
 
 use crate::prelude::*;
 
@@ -25,7 +27,7 @@ pub(crate) fn plugin(app: &mut App) {
             (enemy_collision_system),
             (player_health_sync_system, update_health_bar_system, death_system).chain(),
             (player_invincibility_system),
-        ).run_if(in_state(GameState::Playing)).run_if(any_with_component::<PlayerBall>));
+        ).run_if(in_state(PauseState::Running)).run_if(any_with_component::<PlayerBall>));
 }
 
 fn planetary_control_system(
@@ -132,24 +134,51 @@ fn enemy_collision_system(
     mut commands: Commands,
     dash_state: Res<DashState>,
     player_settings: Res<PlayerSettings>,
+    enemy_settings: Res<EnemySettings>,
     mut q_player: Query<(&GlobalTransform, &mut PlayerBall)>,
-    q_machines: Query<(Entity, &GlobalTransform), With<AlienMachine>>,
-    q_factories: Query<(Entity, &GlobalTransform), With<AlienFactory>>,
+    mut q_machines: Query<(Entity, &GlobalTransform, &mut AlienMachine, &MachineKind)>,
+    mut q_factories: Query<(Entity, &GlobalTransform, &mut Health), With<AlienFactory>>,
     mut score_msg: MessageWriter<ScoreMessage>,
+    mut destroyed_msg: MessageWriter<FactoryDestroyed>,
+    mut kamikaze_msg: MessageWriter<KamikazeContact>,
+    mut stats: ResMut<RunStats>,
+    mut struck: Local<Vec<Entity>>,
 ) {
     let Ok((player_gtrans, mut player)) = q_player.single_mut() else { return; };
     let player_pos = player_gtrans.translation();
     let player_radius = player_settings.player_radius;
 
-    for (entity, machine_gtrans) in q_machines.iter() {
-        if player_pos.distance(machine_gtrans.translation()) < player_settings.player_radius + 3.0 {
-            if dash_state.is_active {
-                commands.entity(entity).despawn_children();
-                commands.entity(entity).despawn();
-                score_msg.write(ScoreMessage(300));
-            } else if !player_settings.god_mode && player.invincibility_timer <= 0.0 {
-                player.hp = (player.hp - 25.0).max(0.0);
-                player.invincibility_timer = 5.0; 
+    // Tanks and factories take one hit per dash, however long the ball stays in contact.
+    if !dash_state.is_active { struck.clear(); }
+
+    for (entity, machine_gtrans, mut machine, kind) in q_machines.iter_mut() {
+        let archetype = enemy_settings.archetypes.get(*kind);
+        if player_pos.distance(machine_gtrans.translation()) >= player_radius + archetype.size * 0.5 { continue; }
+
+        if dash_state.is_active {
+            if struck.contains(&entity) { continue; }
+            if *kind == MachineKind::Tank {
+                machine.stun = enemy_settings.tank_stun_secs;
+                struck.push(entity);
+                continue;
+            }
+            commands.entity(entity).despawn_children();
+            commands.entity(entity).despawn();
+            score_msg.write(ScoreMessage(archetype.score));
+            stats.machines_destroyed += 1;
+        } else if *kind == MachineKind::Kamikaze {
+            // The blast does the damage
+            if machine.stun <= 0.0 {
+                kamikaze_msg.write(KamikazeContact { machine: entity });
+            }
+        } else if machine.stun <= 0.0
+            && archetype.contact_damage > 0.0
+            && !player_settings.god_mode
+            && player.invincibility_timer <= 0.0
+        {
+            player.hp = (player.hp - archetype.contact_damage).max(0.0);
+            player.invincibility_timer = 5.0; 
+            if *kind != MachineKind::Tank {
                 commands.entity(entity).despawn_children();
                 commands.entity(entity).despawn();
             }
@@ -157,11 +186,24 @@ fn enemy_collision_system(
     }
 
     if dash_state.is_active {
-        for (entity, factory_gtrans) in q_factories.iter() {
+        // A bigger, faster ball hits harder, up to `factory_dash_damage`
+        let size_factor = (player_radius / player_settings.max_hp_radius).min(1.0);
+        let speed_factor = (player.current_velocity.length() / enemy_settings.factory_damage_reference_speed).min(1.0);
+        let damage = enemy_settings.factory_dash_damage * size_factor * speed_factor;
+
+        for (entity, factory_gtrans, mut health) in q_factories.iter_mut() {
+            if struck.contains(&entity) { continue; }
             if player_pos.distance(factory_gtrans.translation()) < player_radius + 6.0 {
+                struck.push(entity);
+                health.current -= damage;
+                health.regen_cooldown = enemy_settings.factory_regen_delay;
+                if health.current > 0.0 { continue; }
+
                 commands.entity(entity).despawn_children();
                 commands.entity(entity).despawn();
-                score_msg.write(ScoreMessage(500));
+                score_msg.write(ScoreMessage(enemy_settings.factory_score));
+                destroyed_msg.write(FactoryDestroyed { position: factory_gtrans.translation() });
+                stats.factories_destroyed += 1;
             }
         }
     }
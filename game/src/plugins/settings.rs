@@ -0,0 +1,195 @@
+//! # Settings Plugin
+//!
+//! Keeps the player's name, joystick tuning and audio/graphics preferences in
+//! `settings.json` under the user config directory.
+//!
+//! The file is read once while the app is built, so every other plugin already sees
+//! the saved values on the first frame. Changes are written back a second after the
+//! last edit, and again when leaving the main menu.
+
+use bevy::prelude::*;
+use bevy::audio::{GlobalVolume, Volume};
+use bevy::window::{PresentMode, PrimaryWindow};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::prelude::{
+    player_profile::PlayerProfile,
+    user_preferences::UserPreferences,
+    vjoy_config::VjoyConfig,
+};
+use crate::storage::{config_path, load_json, save_json};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+/// Bump when a field changes meaning; older files are migrated in [SettingsFile::load].
+const SETTINGS_VERSION: u32 = 1;
+
+/// Seconds to wait after the last change before writing the file.
+const SAVE_DELAY_SECS: f32 = 1.0;
+
+pub(crate) fn plugin(app: &mut App) {
+    let file = SettingsFile::load();
+
+    let mut vjoy = VjoyConfig::default();
+    file.controls.apply(&mut vjoy);
+
+    app
+        .insert_resource(PlayerProfile { username: file.username })
+        .insert_resource(vjoy)
+        .insert_resource(file.preferences)
+        .register_type::<UserPreferences>()
+        .add_systems(Update, (
+            apply_preferences_system,
+            autosave_settings_system,
+        ))
+        .add_systems(OnExit(GameState::MainMenu), save_settings_system);
+}
+
+/// On-disk layout of `settings.json`. Missing fields fall back to their defaults.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct SettingsFile {
+    version: u32,
+    username: String,
+    controls: ControlSettings,
+    preferences: UserPreferences,
+}
+
+impl Default for SettingsFile {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            username: String::new(),
+            controls: ControlSettings::from(&VjoyConfig::default()),
+            preferences: UserPreferences::default(),
+        }
+    }
+}
+
+impl SettingsFile {
+    fn load() -> Self {
+        let Some(file) = load_json::<SettingsFile>(&config_path(SETTINGS_FILE)) else {
+            return Self::default();
+        };
+
+        if file.version > SETTINGS_VERSION {
+            warn!("SETTINGS: file is from a newer version ({}), using defaults", file.version);
+            return Self::default();
+        }
+
+        Self { version: SETTINGS_VERSION, ..file }
+    }
+
+    fn save(profile: &PlayerProfile, vjoy: &VjoyConfig, preferences: &UserPreferences) {
+        let file = Self {
+            version: SETTINGS_VERSION,
+            username: profile.username.clone(),
+            controls: ControlSettings::from(vjoy),
+            preferences: preferences.clone(),
+        };
+        save_json(&config_path(SETTINGS_FILE), &file);
+    }
+}
+
+/// The player-tunable part of [VjoyConfig]. Pixel clamps and colours stay in code.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct ControlSettings {
+    sensitivity: f32,
+    deadzone: f32,
+    alpha_idle: f32,
+    alpha_active: f32,
+    base_size_vmin: f32,
+    knob_size_vmin: f32,
+    pos_left_vmin: f32,
+    pos_bottom_vmin: f32,
+}
+
+impl Default for ControlSettings {
+    fn default() -> Self {
+        Self::from(&VjoyConfig::default())
+    }
+}
+
+impl From<&VjoyConfig> for ControlSettings {
+    fn from(config: &VjoyConfig) -> Self {
+        Self {
+            sensitivity: config.sensitivity,
+            deadzone: config.deadzone,
+            alpha_idle: config.alpha_idle,
+            alpha_active: config.alpha_active,
+            base_size_vmin: config.base_size_vmin,
+            knob_size_vmin: config.knob_size_vmin,
+            pos_left_vmin: config.pos_left_vmin,
+            pos_bottom_vmin: config.pos_bottom_vmin,
+        }
+    }
+}
+
+impl ControlSettings {
+    fn apply(&self, config: &mut VjoyConfig) {
+        config.sensitivity = self.sensitivity;
+        config.deadzone = self.deadzone.clamp(0.0, 1.0);
+        config.alpha_idle = self.alpha_idle.clamp(0.0, 1.0);
+        config.alpha_active = self.alpha_active.clamp(0.0, 1.0);
+        config.base_size_vmin = self.base_size_vmin;
+        config.knob_size_vmin = self.knob_size_vmin;
+        config.pos_left_vmin = self.pos_left_vmin;
+        config.pos_bottom_vmin = self.pos_bottom_vmin;
+    }
+}
+
+/// Pushes [UserPreferences] into the audio mixer, the window and the sun light.
+fn apply_preferences_system(
+    preferences: Res<UserPreferences>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut q_window: Query<&mut Window, With<PrimaryWindow>>,
+    mut q_lights: Query<&mut DirectionalLight>,
+) {
+    if !preferences.is_changed() {
+        return;
+    }
+
+    global_volume.volume = Volume::Linear(preferences.master_volume.clamp(0.0, 1.0));
+
+    let present_mode = if preferences.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync };
+    for mut window in q_window.iter_mut() {
+        window.present_mode = present_mode;
+    }
+
+    for mut light in q_lights.iter_mut() {
+        light.shadows_enabled = preferences.shadows;
+    }
+}
+
+/// Saves once no setting has changed for [SAVE_DELAY_SECS], so dragging a value
+/// in the inspector or typing a name doesn't hit the disk every frame.
+fn autosave_settings_system(
+    time: Res<Time>,
+    profile: Res<PlayerProfile>,
+    vjoy: Res<VjoyConfig>,
+    preferences: Res<UserPreferences>,
+    mut save_at: Local<Option<f32>>,
+) {
+    let edited = [profile.is_changed() && !profile.is_added(),
+                  vjoy.is_changed() && !vjoy.is_added(),
+                  preferences.is_changed() && !preferences.is_added()];
+
+    if edited.contains(&true) {
+        *save_at = Some(time.elapsed_secs() + SAVE_DELAY_SECS);
+    }
+
+    if save_at.is_some_and(|at| time.elapsed_secs() >= at) {
+        *save_at = None;
+        SettingsFile::save(&profile, &vjoy, &preferences);
+    }
+}
+
+fn save_settings_system(
+    profile: Res<PlayerProfile>,
+    vjoy: Res<VjoyConfig>,
+    preferences: Res<UserPreferences>,
+) {
+    SettingsFile::save(&profile, &vjoy, &preferences);
+}
\ No newline at end of file
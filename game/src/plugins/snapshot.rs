@@ -0,0 +1,245 @@
+//! # Snapshot Plugin
+//!
+//! Saves the run in progress to `session.json` when the player quits to the main menu
+//! or closes the game mid-run, and rebuilds it when they pick "Continue".
+//!
+//! A resumed run starts like a fresh one (ball, factories, seed) and is then
+//! overwritten from the snapshot, so a snapshot that no longer fits the planet is
+//! simply dropped and the player gets a new run instead.
+//! The snapshot is deleted once it has been resumed or the run ends.
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::prelude::{
+    dash_state::DashState,
+    enemy_settings::EnemySettings,
+    factory::*,
+    game_rng::*,
+    machine::{AlienMachine, EnemyBrain, MachineKind},
+    orbs::EnergyOrb,
+    planet::*,
+    planet_pivot::PlanetPivot,
+    player_ball::PlayerBall,
+    reset_target::ResetTarget,
+    run_stats::RunStats,
+    score::Score,
+    session_snapshot::*,
+    session_time::SessionTime,
+    tile_changes::TileChangeCause,
+    ui::SessionUi,
+};
+use crate::plugins::game::TileWriter;
+use crate::storage::{data_path, load_json, save_json};
+
+pub(crate) fn plugin(app: &mut App) {
+    let snapshot = load_json::<SessionSnapshot>(&data_path(SESSION_SNAPSHOT_FILE))
+        .filter(|snapshot| snapshot.version == SNAPSHOT_VERSION);
+
+    app
+        .insert_resource(SavedSession { snapshot, resume: false })
+        .add_systems(
+            OnTransition { exited: GameState::Playing, entered: GameState::Resetting },
+            save_session_system.run_if(|target: Res<ResetTarget>| target.0 == GameState::MainMenu),
+        )
+        .add_systems(Last, save_session_system.run_if(in_state(GameState::Playing).and(on_message::<AppExit>)))
+        .add_systems(OnEnter(GameState::Playing), restore_session_system
+            .after(crate::plugins::game::spawn_session_objects)
+            .after(crate::plugins::enemies::spawn_factories))
+        .add_systems(OnEnter(GameState::GameOver), discard_session_system)
+        .add_systems(OnEnter(GameState::Victory), discard_session_system);
+}
+
+/// Captures the live world into a [SessionSnapshot] and writes it to disk.
+fn save_session_system(
+    mut saved: ResMut<SavedSession>,
+    seed: Res<GameSeed>,
+    rng: Res<GameRng>,
+    score: Res<Score>,
+    time: Res<SessionTime>,
+    stats: Res<RunStats>,
+    dash: Res<DashState>,
+    enemy_settings: Res<EnemySettings>,
+    q_planet: Query<&PlanetTiles, With<Planet>>,
+    q_pivot: Query<&Transform, With<PlanetPivot>>,
+    q_player: Query<&PlayerBall>,
+    q_factories: Query<(&Transform, &FactorySpawner, &Health), With<AlienFactory>>,
+    q_machines: Query<(&Transform, &AlienMachine, &EnemyBrain, &MachineKind)>,
+    q_orbs: Query<&Transform, With<EnergyOrb>>,
+) {
+    let Ok(tiles) = q_planet.single() else { return; };
+    let Ok(pivot) = q_pivot.single() else { return; };
+    let Ok(player) = q_player.single() else { return; };
+
+    let snapshot = SessionSnapshot {
+        version: SNAPSHOT_VERSION,
+        seed: seed.current,
+        rng_word_pos: rng.word_pos(),
+        score: score.current,
+        elapsed: time.elapsed,
+        stats: *stats,
+        difficulty_scale: enemy_settings.difficulty_scale,
+        factory_spawn_elapsed: enemy_settings.factory_spawn_timer.elapsed_secs(),
+        tiles: encode_tiles(tiles.tiles.iter().map(|tile| tile.state)),
+        pivot_rotation: pivot.rotation.to_array(),
+        player: PlayerSnapshot {
+            velocity: player.current_velocity.to_array(),
+            hp: player.hp,
+            invincibility_timer: player.invincibility_timer,
+        },
+        dash: dash.clone(),
+        factories: q_factories.iter().map(|(transform, spawner, health)| FactorySnapshot {
+            position: transform.translation.to_array(),
+            spawn_interval: spawner.timer.duration().as_secs_f32(),
+            spawn_elapsed: spawner.timer.elapsed_secs(),
+            hp: health.current,
+            regen_cooldown: health.regen_cooldown,
+        }).collect(),
+        machines: q_machines.iter().map(|(transform, machine, brain, kind)| MachineSnapshot {
+            position: transform.translation.to_array(),
+            velocity: machine.velocity.to_array(),
+            kind: *kind,
+            stun: machine.stun,
+            cooldown: machine.cooldown,
+            state: brain.state,
+            home: brain.home.to_array(),
+            waypoint: brain.waypoint.to_array(),
+            state_timer: brain.timer,
+        }).collect(),
+        orbs: q_orbs.iter().map(|transform| transform.translation.to_array()).collect(),
+    };
+
+    save_json(&data_path(SESSION_SNAPSHOT_FILE), &snapshot);
+    info!("SNAPSHOT: saved run at {} with score {}", time.format(), score.current);
+    saved.snapshot = Some(snapshot);
+}
+
+/// Replaces the freshly spawned run with the saved one when resuming.
+fn restore_session_system(
+    mut commands: Commands,
+    mut saved: ResMut<SavedSession>,
+    mut seed: ResMut<GameSeed>,
+    mut rng: ResMut<GameRng>,
+    mut score: ResMut<Score>,
+    mut time: ResMut<SessionTime>,
+    mut stats: ResMut<RunStats>,
+    mut dash: ResMut<DashState>,
+    mut enemy_settings: ResMut<EnemySettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+    mut tiles: TileWriter,
+    mut q_pivot: Query<&mut Transform, With<PlanetPivot>>,
+    mut q_player: Query<&mut PlayerBall>,
+    q_factories: Query<Entity, With<AlienFactory>>,
+) {
+    if !saved.resume { return; }
+    saved.resume = false;
+    let Some(snapshot) = saved.snapshot.take() else { return; };
+    discard_snapshot_file();
+
+    let Some(tile_count) = tiles.tiles().map(PlanetTiles::len) else { return; };
+
+    let Some(states) = decode_tiles(&snapshot.tiles) else {
+        warn!("SNAPSHOT: unreadable tile data, starting a new run");
+        return;
+    };
+    if states.len() != tile_count {
+        warn!("SNAPSHOT: planet size changed, starting a new run");
+        return;
+    }
+
+    tiles.set_each(states.into_iter().enumerate(), TileChangeCause::Restore);
+
+    if let Ok(mut pivot) = q_pivot.single_mut() {
+        pivot.rotation = Quat::from_array(snapshot.pivot_rotation).normalize();
+    }
+    if let Ok(mut player) = q_player.single_mut() {
+        player.current_velocity = Vec3::from_array(snapshot.player.velocity);
+        player.hp = snapshot.player.hp;
+        player.invincibility_timer = snapshot.player.invincibility_timer;
+    }
+
+    seed.current = snapshot.seed;
+    *rng = GameRng::resume(snapshot.seed, snapshot.rng_word_pos);
+
+    score.current = snapshot.score;
+    time.elapsed = snapshot.elapsed;
+    *stats = snapshot.stats;
+    *dash = snapshot.dash;
+    enemy_settings.difficulty_scale = snapshot.difficulty_scale;
+    enemy_settings.factory_spawn_timer.set_elapsed(std::time::Duration::from_secs_f32(snapshot.factory_spawn_elapsed));
+
+    for entity in q_factories.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let (factory_mesh, factory_mat) = crate::plugins::enemies::factory_assets(&mut meshes, &mut materials, &asset_server);
+    for factory in &snapshot.factories {
+        let pos = Vec3::from_array(factory.position);
+        let mut timer = Timer::from_seconds(factory.spawn_interval, TimerMode::Repeating);
+        timer.set_elapsed(std::time::Duration::from_secs_f32(factory.spawn_elapsed));
+
+        commands.spawn((
+            AlienFactory,
+            FactorySpawner { timer },
+            Health {
+                current: factory.hp.min(enemy_settings.factory_max_hp),
+                regen_cooldown: factory.regen_cooldown,
+                ..Health::full(enemy_settings.factory_max_hp)
+            },
+            DamageStage::default(),
+            Mesh3d(factory_mesh.clone()),
+            MeshMaterial3d(factory_mat.clone()),
+            Transform::from_translation(pos).looking_at(pos + pos.normalize(), Vec3::Y),
+            SessionUi,
+        ));
+    }
+
+    let mut machine_assets = std::collections::HashMap::new();
+    for machine in &snapshot.machines {
+        let (machine_mesh, machine_mat) = machine_assets.entry(machine.kind).or_insert_with(|| {
+            crate::plugins::enemies::machine_assets(&mut meshes, &mut materials, &asset_server, enemy_settings.archetypes.get(machine.kind))
+        });
+        commands.spawn((
+            AlienMachine { stun: machine.stun, cooldown: machine.cooldown, ..AlienMachine::new(Vec3::from_array(machine.velocity)) },
+            EnemyBrain {
+                state: machine.state,
+                home: Vec3::from_array(machine.home),
+                waypoint: Vec3::from_array(machine.waypoint),
+                timer: machine.state_timer,
+            },
+            machine.kind,
+            Mesh3d(machine_mesh.clone()),
+            MeshMaterial3d(machine_mat.clone()),
+            Transform::from_translation(Vec3::from_array(machine.position)),
+        ));
+    }
+
+    let (orb_mesh, orb_mat) = crate::plugins::game::orb_assets(&mut meshes, &mut materials);
+    for orb in &snapshot.orbs {
+        commands.spawn((
+            EnergyOrb,
+            Mesh3d(orb_mesh.clone()),
+            MeshMaterial3d(orb_mat.clone()),
+            Transform::from_translation(Vec3::from_array(*orb)),
+        ));
+    }
+
+    info!("SNAPSHOT: resumed run at {} with score {}", time.format(), score.current);
+}
+
+/// A finished run can't be continued.
+fn discard_session_system(mut saved: ResMut<SavedSession>) {
+    if saved.snapshot.take().is_some() {
+        discard_snapshot_file();
+    }
+}
+
+fn discard_snapshot_file() {
+    let path = data_path(SESSION_SNAPSHOT_FILE);
+    if let Err(e) = std::fs::remove_file(&path)
+        && e.kind() != std::io::ErrorKind::NotFound {
+        warn!("SNAPSHOT: failed to delete {}: {}", path.display(), e);
+    }
+}
\ No newline at end of file
@@ -26,6 +26,7 @@ use crate::prelude::{
 };
 
 use crate::components::ui::*;
+use crate::states::PauseState;
 
 /// Main entry point for the Virtual Joystick functionality.
 /// Call `.add_plugins(vjoy::plugin)` in your App setup.
@@ -43,7 +44,7 @@ pub(crate) fn plugin(app: &mut App) {
         .add_systems(Update, (
             joystick_input_system.run_if(any_with_component::<VjoyBase>), 
             joystick_render_system.run_if(any_with_component::<VjoyBase>),
-            dash_input_system.run_if(any_with_component::<DashButton>),
+            dash_input_system.run_if(any_with_component::<DashButton>).run_if(in_state(PauseState::Running)),
         ).chain());
 }
 
@@ -130,12 +131,11 @@ fn joystick_input_system(
             input_released = true;
         }
 
-        if !input_released {
-            if let Some(pos) = relative_cursor.normalized {
-                let joystick_vec = Vec2::new(pos.x * config.sensitivity, -pos.y * config.sensitivity);
-                vjoy_output.dir = joystick_vec.clamp(Vec2::splat(-1.0), Vec2::splat(1.0));
-                if vjoy_output.dir.length() < config.deadzone { vjoy_output.dir = Vec2::ZERO; }
-            }
+        if !input_released
+            && let Some(pos) = relative_cursor.normalized {
+            let joystick_vec = Vec2::new(pos.x * config.sensitivity, -pos.y * config.sensitivity);
+            vjoy_output.dir = joystick_vec.clamp(Vec2::splat(-1.0), Vec2::splat(1.0));
+            if vjoy_output.dir.length() < config.deadzone { vjoy_output.dir = Vec2::ZERO; }
         }
     }
 
@@ -278,12 +278,13 @@ fn dash_input_system(
         meter_bg.0 = Color::srgba(0.0, 0.5, 0.5, 0.3);
     }
 
-    if *interaction == Interaction::Pressed && is_ready {
+    if (*interaction == Interaction::Pressed || state.requested) && is_ready {
         state.is_active = true;
         state.duration_timer = settings.dash_duration;
         state.current_energy = 0.0;
         state.cooldown_timer = settings.cooldown_secs;
     }
+    state.requested = false;
 
     let energy_pct = state.current_energy / settings.max_energy;
     meter_node.width = Val::Percent(energy_pct * 100.0);
@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fmt;
+
+use super::dash_settings::DashSettings;
+use super::enemy_settings::EnemySettings;
+use super::planet_settings::PlanetSettings;
+use crate::components::machine::MachineKind;
+use super::player_settings::PlayerSettings;
+
+/// Asset path of the balance file, relative to the `assets` folder.
+pub const BALANCE_ASSET: &str = "config/game.balance.ron";
+
+/// Gameplay tuning read from [BALANCE_ASSET].
+///
+/// Every section and field is optional; anything left out keeps its `Default` value.
+#[derive(Asset, TypePath, Deserialize, Debug, Default, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct BalanceConfig {
+    pub planet: PlanetSettings,
+    pub player: PlayerSettings,
+    pub enemies: EnemySettings,
+    pub dash: DashSettings,
+}
+
+#[derive(Debug)]
+pub enum BalanceError {
+    Io(String),
+    /// Not valid RON, or a field has the wrong name or type.
+    Parse(String),
+    /// Parsed fine, but some values are out of range. One message per field.
+    Invalid(Vec<String>),
+}
+
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "can't read balance file: {}", e),
+            Self::Parse(e) => write!(f, "balance file is malformed: {}", e),
+            Self::Invalid(problems) => write!(f, "balance file has bad values: {}", problems.join("; ")),
+        }
+    }
+}
+
+impl std::error::Error for BalanceError {}
+
+impl BalanceConfig {
+    /// Parses and validates a balance file.
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, BalanceError> {
+        let config: Self = ron::de::from_bytes(bytes).map_err(|e| BalanceError::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), BalanceError> {
+        let mut check = Checker::default();
+
+        let planet = &self.planet;
+        check.positive("planet.radius", planet.radius);
+        check.range("planet.subdivisions", planet.subdivisions as f32, 0.0, 79.0);
+        check.range("planet.friction", planet.friction, 0.0, 1.0);
+        check.range("planet.orb_hp_gain", planet.orb_hp_gain, 0.0, 100.0);
+        check.range("planet.orb_spawn_chance", planet.orb_spawn_chance, 0.0, 1.0);
+        check.range("planet.victory_healthy_ratio", planet.victory_healthy_ratio, 0.0, 1.0);
+        check.at_least("planet.par_time", planet.par_time, 0.0);
+        check.at_least("planet.time_bonus_per_sec", planet.time_bonus_per_sec, 0.0);
+
+        let player = &self.player;
+        check.positive("player.acceleration", player.acceleration);
+        check.positive("player.max_speed", player.max_speed);
+        check.positive("player.camera_height", player.camera_height);
+        check.at_least("player.max_hp_radius", player.max_hp_radius, 2.0);
+
+        let enemies = &self.enemies;
+        check.positive("enemies.pollution_radius", enemies.pollution_radius);
+        check.positive("enemies.spread_tick_rate", enemies.spread_tick_rate);
+        check.range("enemies.boosted_spread_chance", enemies.boosted_spread_chance, 0.0, 1.0);
+        check.range("enemies.natural_spread_chance", enemies.natural_spread_chance, 0.0, 1.0);
+        check.positive("enemies.machine_spawn_interval", enemies.machine_spawn_interval);
+        check.at_least("enemies.machine_speed", enemies.machine_speed, 0.0);
+        check.at_least("enemies.machine_detection_range", enemies.machine_detection_range, 0.0);
+        check.at_least("enemies.machine_acceleration", enemies.machine_acceleration, 0.0);
+        check.at_least("enemies.difficulty_growth_rate", enemies.difficulty_growth_rate, 0.0);
+        for (i, channel) in enemies.pollution_color.iter().enumerate() {
+            check.range(&format!("enemies.pollution_color[{i}]"), *channel, 0.0, 1.0);
+        }
+        check.positive("enemies.factory_max_hp", enemies.factory_max_hp);
+        check.range("enemies.factory_dash_damage", enemies.factory_dash_damage, 0.0, enemies.factory_max_hp);
+        check.positive("enemies.factory_damage_reference_speed", enemies.factory_damage_reference_speed);
+        check.at_least("enemies.factory_regen_rate", enemies.factory_regen_rate, 0.0);
+        check.at_least("enemies.factory_regen_delay", enemies.factory_regen_delay, 0.0);
+        check.at_least("enemies.shockwave_radius", enemies.shockwave_radius, 0.0);
+        check.positive("enemies.shockwave_secs", enemies.shockwave_secs);
+        check.positive("enemies.polluter_trail_interval", enemies.polluter_trail_interval);
+        check.at_least("enemies.tank_stun_secs", enemies.tank_stun_secs, 0.0);
+        check.positive("enemies.kamikaze_blast_radius", enemies.kamikaze_blast_radius);
+        check.range("enemies.kamikaze_trigger_range", enemies.kamikaze_trigger_range, 0.0, enemies.kamikaze_blast_radius);
+        check.at_least("enemies.repair_rate", enemies.repair_rate, 0.0);
+        check.at_least("enemies.repair_range", enemies.repair_range, 0.0);
+        check.at_least("enemies.chase_leash", enemies.chase_leash, 1.0);
+        check.positive("enemies.patrol_radius", enemies.patrol_radius);
+        check.at_least("enemies.idle_secs", enemies.idle_secs, 0.0);
+        check.positive("enemies.patrol_secs", enemies.patrol_secs);
+        check.at_least("enemies.separation_radius", enemies.separation_radius, 0.0);
+        check.at_least("enemies.separation_strength", enemies.separation_strength, 0.0);
+        check.positive("enemies.path_costs.wasteland", enemies.path_costs.wasteland);
+        check.positive("enemies.path_costs.healthy", enemies.path_costs.healthy);
+        check.positive("enemies.path_costs.polluted", enemies.path_costs.polluted);
+        check.at_least("enemies.flow_field_refresh_secs", enemies.flow_field_refresh_secs, 0.0);
+        for kind in MachineKind::ALL {
+            let archetype = enemies.archetypes.get(kind);
+            let name = format!("enemies.archetypes.{}", format!("{kind:?}").to_lowercase());
+            check.positive(&format!("{name}.size"), archetype.size);
+            check.at_least(&format!("{name}.speed"), archetype.speed, 0.0);
+            check.at_least(&format!("{name}.acceleration"), archetype.acceleration, 0.0);
+            check.at_least(&format!("{name}.detection_range"), archetype.detection_range, 0.0);
+            check.at_least(&format!("{name}.contact_damage"), archetype.contact_damage, 0.0);
+            check.at_least(&format!("{name}.spawn_weight"), archetype.spawn_weight, 0.0);
+            check.at_least(&format!("{name}.spawn_weight_growth"), archetype.spawn_weight_growth, 0.0);
+            for (i, channel) in archetype.tint.iter().enumerate() {
+                check.range(&format!("{name}.tint[{i}]"), *channel, 0.0, 1.0);
+            }
+        }
+
+        let dash = &self.dash;
+        check.positive("dash.dash_duration", dash.dash_duration);
+        check.positive("dash.max_energy", dash.max_energy);
+        check.range("dash.dash_cost", dash.dash_cost, 0.0, dash.max_energy);
+        check.at_least("dash.regen_rate", dash.regen_rate, 0.0);
+        check.at_least("dash.cooldown_secs", dash.cooldown_secs, 0.0);
+
+        check.finish()
+    }
+}
+
+#[derive(Default)]
+struct Checker {
+    problems: Vec<String>,
+}
+
+impl Checker {
+    fn positive(&mut self, field: &str, value: f32) {
+        if !(value.is_finite() && value > 0.0) {
+            self.problems.push(format!("{field} must be greater than 0 (got {value})"));
+        }
+    }
+
+    fn at_least(&mut self, field: &str, value: f32, min: f32) {
+        if !(value.is_finite() && value >= min) {
+            self.problems.push(format!("{field} must be at least {min} (got {value})"));
+        }
+    }
+
+    fn range(&mut self, field: &str, value: f32, min: f32, max: f32) {
+        if !(value.is_finite() && (min..=max).contains(&value)) {
+            self.problems.push(format!("{field} must be between {min} and {max} (got {value})"));
+        }
+    }
+
+    fn finish(self) -> Result<(), BalanceError> {
+        if self.problems.is_empty() { Ok(()) } else { Err(BalanceError::Invalid(self.problems)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subdivisions_stop_at_the_finest_ico_sphere() {
+        let mut config = BalanceConfig::default();
+        config.planet.subdivisions = 79;
+        assert!(config.validate().is_ok());
+
+        config.planet.subdivisions = 80;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn kamikaze_must_go_off_within_its_blast() {
+        let mut config = BalanceConfig::default();
+        config.enemies.kamikaze_trigger_range = config.enemies.kamikaze_blast_radius;
+        assert!(config.validate().is_ok());
+
+        config.enemies.kamikaze_trigger_range = config.enemies.kamikaze_blast_radius + 1.0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn one_dash_cannot_take_more_than_a_whole_factory() {
+        let mut config = BalanceConfig::default();
+        config.enemies.factory_dash_damage = config.enemies.factory_max_hp;
+        assert!(config.validate().is_ok());
+
+        config.enemies.factory_dash_damage = config.enemies.factory_max_hp * 1.5;
+        assert!(config.validate().is_err());
+    }
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
-#[derive(Resource, Reflect, Debug)]
+#[derive(Resource, Reflect, Deserialize, Debug, Clone)]
 #[reflect(Resource)]
+#[serde(default, deny_unknown_fields)]
 pub struct DashSettings {
     pub dash_force: f32,
     pub dash_duration: f32,
@@ -1,10 +1,14 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Resource, Default, Reflect)]
+#[derive(Resource, Default, Reflect, Serialize, Deserialize, Debug, Clone)]
 #[reflect(Resource)]
 pub struct DashState {
     pub current_energy: f32,
     pub cooldown_timer: f32,
     pub duration_timer: f32,
     pub is_active: bool,
+    /// Set by any input source to ask for a dash; consumed on the next dash update.
+    #[serde(skip)]
+    pub requested: bool,
 }
\ No newline at end of file
@@ -1,7 +1,12 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Deserializer};
 
-#[derive(Resource, Reflect, Debug)]
+use crate::components::machine::MachineKind;
+use crate::components::planet::TileState;
+
+#[derive(Resource, Reflect, Deserialize, Debug, Clone)]
 #[reflect(Resource)]
+#[serde(default, deny_unknown_fields)]
 pub struct EnemySettings {
     pub factory_count: usize,
     pub pollution_radius: f32,
@@ -13,9 +18,229 @@ pub struct EnemySettings {
     pub machine_speed: f32,
     pub machine_detection_range: f32,
     pub machine_acceleration: f32,
+    /// Written as `factory_spawn_interval` (seconds) in balance files.
+    #[serde(rename = "factory_spawn_interval", deserialize_with = "repeating_timer")]
     pub factory_spawn_timer: Timer,
+    /// Grows during a run and is reset with the world, so it isn't read from balance files.
+    #[serde(skip)]
     pub difficulty_scale: f32,
     pub difficulty_growth_rate: f32,
+    pub factory_max_hp: f32,
+    /// Hit points a factory loses to one dash by a full-sized ball moving at
+    /// `factory_damage_reference_speed` or faster. Less for a smaller or slower ball,
+    /// and no more than `factory_max_hp`.
+    pub factory_dash_damage: f32,
+    pub factory_damage_reference_speed: f32,
+    /// Hit points per second a factory gets back once `factory_regen_delay` has passed since its last hit.
+    pub factory_regen_rate: f32,
+    pub factory_regen_delay: f32,
+    /// Points for destroying a factory.
+    pub factory_score: usize,
+    /// Reach of the shockwave a destroyed factory leaves, in world units.
+    pub shockwave_radius: f32,
+    /// Time the shockwave takes to reach `shockwave_radius`.
+    pub shockwave_secs: f32,
+    pub archetypes: MachineArchetypes,
+    /// Seconds between two patches of a polluter drone's trail.
+    pub polluter_trail_interval: f32,
+    /// How long a dash leaves a tank unable to move.
+    pub tank_stun_secs: f32,
+    /// Gap between a kamikaze and the player's surface at which it blows up. At most
+    /// `kamikaze_blast_radius`, so the blast always reaches the player.
+    pub kamikaze_trigger_range: f32,
+    /// Reach of a kamikaze explosion. Tiles this close to the kamikaze are polluted, and
+    /// its `contact_damage` falls off to nothing this far from the player's surface.
+    pub kamikaze_blast_radius: f32,
+    /// Hit points per second a repair drone gives back to factories in range.
+    pub repair_rate: f32,
+    /// How close to a factory a repair drone has to be to tend it.
+    pub repair_range: f32,
+    /// A chase is given up once the player is this many times the detection range away.
+    pub chase_leash: f32,
+    /// How far from its factory a machine patrols.
+    pub patrol_radius: f32,
+    pub idle_secs: f32,
+    /// Time after which a patrol waypoint that hasn't been reached is dropped.
+    pub patrol_secs: f32,
+    /// Machines closer than this push each other apart.
+    pub separation_radius: f32,
+    /// Acceleration of the push between two machines that touch.
+    pub separation_strength: f32,
+    /// How much it costs a chasing machine to cross each kind of tile.
+    pub path_costs: TileCosts,
+    /// Shortest time between two rebuilds of the flow field toward the player.
+    pub flow_field_refresh_secs: f32,
+    /// Tiles along its route that a chasing machine aims ahead, to smooth out the corners.
+    pub path_lookahead: usize,
+}
+
+impl EnemySettings {
+    /// Picks the kind of the next machine from the spawn table at the current difficulty.
+    /// `roll` is a random number in `0..1`.
+    pub fn pick_machine_kind(&self, roll: f32) -> MachineKind {
+        let weights = MachineKind::ALL.map(|kind| self.archetypes.get(kind).weight_at(self.difficulty_scale));
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 { return MachineKind::Chaser; }
+
+        let mut remaining = roll * total;
+        for (kind, weight) in MachineKind::ALL.into_iter().zip(weights) {
+            if remaining < weight { return kind; }
+            remaining -= weight;
+        }
+        MachineKind::ALL.into_iter().zip(weights).rfind(|(_, weight)| *weight > 0.0).map_or(MachineKind::Chaser, |(kind, _)| kind)
+    }
+}
+
+/// Cost of crossing a tile, per unit of distance, by state.
+#[derive(Reflect, Deserialize, Debug, Clone, Copy)]
+#[serde(default, deny_unknown_fields)]
+pub struct TileCosts {
+    pub wasteland: f32,
+    pub healthy: f32,
+    pub polluted: f32,
+}
+
+impl TileCosts {
+    pub fn get(&self, state: TileState) -> f32 {
+        match state {
+            TileState::Wasteland => self.wasteland,
+            TileState::Healthy => self.healthy,
+            TileState::Polluted => self.polluted,
+        }
+    }
+}
+
+impl Default for TileCosts {
+    fn default() -> Self {
+        Self { wasteland: 1.0, healthy: 3.0, polluted: 0.5 }
+    }
+}
+
+/// Stats of one kind of machine. `speed`, `acceleration` and `detection_range` scale the
+/// `machine_*` values of [EnemySettings].
+///
+/// Fields left out of a balance file take the values below, not the kind's own defaults.
+#[derive(Reflect, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct MachineArchetype {
+    /// Billboard texture, relative to the `assets` folder.
+    pub sprite: String,
+    pub tint: [f32; 4],
+    /// Side of the billboard quad.
+    pub size: f32,
+    pub speed: f32,
+    pub acceleration: f32,
+    pub detection_range: f32,
+    /// HP the player loses when hit without dashing. For a kamikaze, the damage of a
+    /// blast right on the player.
+    pub contact_damage: f32,
+    /// Points for destroying one.
+    pub score: usize,
+    /// Weight in the spawn table at difficulty 1.
+    pub spawn_weight: f32,
+    /// Added to the weight for every point of difficulty above 1.
+    pub spawn_weight_growth: f32,
+}
+
+impl MachineArchetype {
+    pub fn weight_at(&self, difficulty: f32) -> f32 {
+        (self.spawn_weight + self.spawn_weight_growth * (difficulty - 1.0)).max(0.0)
+    }
+
+    pub fn color(&self) -> Color {
+        let [r, g, b, a] = self.tint;
+        Color::srgba(r, g, b, a)
+    }
+}
+
+impl Default for MachineArchetype {
+    fn default() -> Self {
+        Self {
+            sprite: "textures/machine.png".to_string(),
+            tint: [1.0, 1.0, 1.0, 1.0],
+            size: 6.0,
+            speed: 1.0,
+            acceleration: 1.0,
+            detection_range: 1.0,
+            contact_damage: 25.0,
+            score: 300,
+            spawn_weight: 0.0,
+            spawn_weight_growth: 0.0,
+        }
+    }
+}
+
+#[derive(Reflect, Deserialize, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct MachineArchetypes {
+    pub chaser: MachineArchetype,
+    pub polluter: MachineArchetype,
+    pub tank: MachineArchetype,
+    pub kamikaze: MachineArchetype,
+    pub repair: MachineArchetype,
+}
+
+impl MachineArchetypes {
+    pub fn get(&self, kind: MachineKind) -> &MachineArchetype {
+        match kind {
+            MachineKind::Chaser => &self.chaser,
+            MachineKind::Polluter => &self.polluter,
+            MachineKind::Tank => &self.tank,
+            MachineKind::Kamikaze => &self.kamikaze,
+            MachineKind::Repair => &self.repair,
+        }
+    }
+}
+
+impl Default for MachineArchetypes {
+    fn default() -> Self {
+        let base = MachineArchetype::default();
+        Self {
+            chaser: MachineArchetype { spawn_weight: 10.0, ..base.clone() },
+            polluter: MachineArchetype {
+                tint: [0.6, 1.0, 0.4, 1.0],
+                speed: 0.5,
+                acceleration: 0.6,
+                detection_range: 0.5,
+                contact_damage: 15.0,
+                score: 200,
+                spawn_weight: 4.0,
+                spawn_weight_growth: 2.0,
+                ..base.clone()
+            },
+            tank: MachineArchetype {
+                tint: [0.6, 0.6, 1.0, 1.0],
+                size: 9.0,
+                speed: 0.4,
+                acceleration: 0.5,
+                contact_damage: 40.0,
+                score: 600,
+                spawn_weight_growth: 1.5,
+                ..base.clone()
+            },
+            kamikaze: MachineArchetype {
+                tint: [1.0, 0.5, 0.2, 1.0],
+                size: 5.0,
+                speed: 1.8,
+                acceleration: 2.5,
+                detection_range: 1.2,
+                contact_damage: 30.0,
+                score: 250,
+                spawn_weight_growth: 2.0,
+                ..base.clone()
+            },
+            repair: MachineArchetype {
+                tint: [0.4, 1.0, 1.0, 1.0],
+                size: 5.0,
+                speed: 0.8,
+                detection_range: 0.0,
+                contact_damage: 0.0,
+                score: 400,
+                spawn_weight_growth: 1.0,
+                ..base
+            },
+        }
+    }
 }
 
 impl Default for EnemySettings {
@@ -34,6 +259,38 @@ impl Default for EnemySettings {
             factory_spawn_timer: Timer::from_seconds(30.0, TimerMode::Repeating),
             difficulty_scale: 1.0,
             difficulty_growth_rate: 0.01,
+            factory_max_hp: 100.0,
+            factory_dash_damage: 50.0,
+            factory_damage_reference_speed: 80.0,
+            factory_regen_rate: 2.0,
+            factory_regen_delay: 5.0,
+            factory_score: 500,
+            shockwave_radius: 40.0,
+            shockwave_secs: 0.8,
+            archetypes: MachineArchetypes::default(),
+            polluter_trail_interval: 0.5,
+            tank_stun_secs: 3.0,
+            kamikaze_trigger_range: 10.0,
+            kamikaze_blast_radius: 15.0,
+            repair_rate: 5.0,
+            repair_range: 20.0,
+            chase_leash: 1.25,
+            patrol_radius: 30.0,
+            idle_secs: 2.0,
+            patrol_secs: 6.0,
+            separation_radius: 12.0,
+            separation_strength: 300.0,
+            path_costs: TileCosts::default(),
+            flow_field_refresh_secs: 0.2,
+            path_lookahead: 3,
         }
     }
+}
+
+fn repeating_timer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timer, D::Error> {
+    let secs = f32::deserialize(deserializer)?;
+    if !secs.is_finite() || secs <= 0.0 {
+        return Err(serde::de::Error::custom(format!("factory_spawn_interval must be a positive number of seconds (got {secs})")));
+    }
+    Ok(Timer::from_seconds(secs, TimerMode::Repeating))
 }
\ No newline at end of file
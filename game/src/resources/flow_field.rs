@@ -0,0 +1,144 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use bevy::prelude::*;
+
+use crate::components::planet::{PlanetTiles, TileState};
+
+/// Cheapest routes from every tile to one goal tile over the planet's tile graph.
+///
+/// Built with Dijkstra outward from the goal, so one field serves every machine
+/// heading for the same place.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct FlowField {
+    goal: Option<usize>,
+    /// Neighbour to step to from each tile. `None` on the goal and unreachable tiles.
+    next: Vec<Option<usize>>,
+}
+
+impl FlowField {
+    /// Routes every tile to `goal`. Stepping between two neighbours costs the distance
+    /// between their centres times the average of their `tile_cost`; tiles with an
+    /// infinite cost are never entered.
+    pub fn build(tiles: &PlanetTiles, goal: usize, tile_cost: impl Fn(TileState) -> f32) -> Self {
+        let costs: Vec<f32> = tiles.tiles.iter().map(|tile| tile_cost(tile.state)).collect();
+        // Cost of the cheapest known path from each tile to the goal
+        let mut cost = vec![f32::INFINITY; tiles.len()];
+        let mut next = vec![None; tiles.len()];
+
+        // Costs are never negative, so their bit patterns sort the same way as the values
+        let mut open = BinaryHeap::new();
+        cost[goal] = 0.0;
+        open.push(Reverse((0.0f32.to_bits(), goal)));
+
+        while let Some(Reverse((bits, current))) = open.pop() {
+            if f32::from_bits(bits) > cost[current] { continue; }
+            let tile = &tiles.tiles[current];
+
+            for &neighbor in &tile.neighbors {
+                if !costs[neighbor].is_finite() { continue; }
+                let step = tile.centroid.distance(tiles.tiles[neighbor].centroid) * (costs[current] + costs[neighbor]) * 0.5;
+                let through = cost[current] + step;
+                if through < cost[neighbor] {
+                    cost[neighbor] = through;
+                    next[neighbor] = Some(current);
+                    open.push(Reverse((through.to_bits(), neighbor)));
+                }
+            }
+        }
+
+        Self { goal: Some(goal), next }
+    }
+
+    pub fn goal(&self) -> Option<usize> {
+        self.goal
+    }
+
+    /// Number of tiles the field was built for.
+    pub fn tile_count(&self) -> usize {
+        self.next.len()
+    }
+
+    pub fn next(&self, tile: usize) -> Option<usize> {
+        self.next.get(tile).copied().flatten()
+    }
+
+    /// Tile `steps` moves along the route from `tile`, stopping early at the goal.
+    /// `None` if `tile` can't reach the goal.
+    pub fn ahead(&self, tile: usize, steps: usize) -> Option<usize> {
+        if Some(tile) != self.goal && self.next(tile).is_none() { return None; }
+        let mut current = tile;
+        for _ in 0..steps {
+            let Some(next) = self.next(current) else { break; };
+            current = next;
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::components::planet::Tile;
+    use crate::spatial::SphereIndex;
+
+    use super::*;
+
+    fn tile(lon: f32, lat: f32, state: TileState, neighbors: &[usize]) -> Tile {
+        let (lon, lat) = (lon.to_radians(), lat.to_radians());
+        Tile {
+            centroid: Vec3::new(lat.cos() * lon.cos(), lat.sin(), lat.cos() * lon.sin()),
+            neighbors: neighbors.to_vec(),
+            state,
+            chunk: 0,
+            vertices: 0..0,
+        }
+    }
+
+    /// Two equally long ways from 0 to the goal 2: through cheap 1 or dear 3.
+    /// 4 only connects through 5, which can't be entered, and 6 has no neighbours.
+    fn graph() -> PlanetTiles {
+        let tiles = vec![
+            tile(0.0, 0.0, TileState::Wasteland, &[1, 3]),
+            tile(10.0, 5.0, TileState::Wasteland, &[0, 2]),
+            tile(20.0, 0.0, TileState::Wasteland, &[1, 3, 5]),
+            tile(10.0, -5.0, TileState::Healthy, &[0, 2]),
+            tile(40.0, 0.0, TileState::Wasteland, &[5]),
+            tile(30.0, 0.0, TileState::Polluted, &[2, 4]),
+            tile(90.0, 0.0, TileState::Wasteland, &[]),
+        ];
+        let centroids: Vec<Vec3> = tiles.iter().map(|tile| tile.centroid).collect();
+        PlanetTiles { tiles, index: SphereIndex::build(&centroids), chunk_count: 1 }
+    }
+
+    fn cost(state: TileState) -> f32 {
+        match state {
+            TileState::Wasteland => 1.0,
+            TileState::Healthy => 3.0,
+            TileState::Polluted => f32::INFINITY,
+        }
+    }
+
+    #[test]
+    fn steps_along_the_cheapest_route() {
+        let field = FlowField::build(&graph(), 2, cost);
+        assert_eq!(field.goal(), Some(2));
+        assert_eq!(field.tile_count(), 7);
+
+        assert_eq!(field.next(0), Some(1));
+        assert_eq!(field.next(1), Some(2));
+        assert_eq!(field.next(3), Some(2));
+        assert_eq!(field.ahead(0, 1), Some(1));
+        assert_eq!(field.ahead(0, 5), Some(2));
+        assert_eq!(field.ahead(2, 3), Some(2));
+    }
+
+    #[test]
+    fn unreachable_tiles_have_no_route() {
+        let field = FlowField::build(&graph(), 2, cost);
+        assert_eq!(field.next(2), None);
+        for tile in [4, 5, 6] {
+            assert_eq!(field.next(tile), None);
+            assert_eq!(field.ahead(tile, 1), None);
+        }
+    }
+}
\ No newline at end of file
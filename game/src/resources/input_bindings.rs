@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+/// Keyboard and gamepad bindings for the gameplay actions.
+///
+/// Physical devices are mapped onto the same [VjoyOutput](super::vjoy_output::VjoyOutput)
+/// direction and [DashState](super::dash_state::DashState) trigger that the on-screen controls use.
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct InputBindings {
+    pub move_up: Vec<KeyCode>,
+    pub move_down: Vec<KeyCode>,
+    pub move_left: Vec<KeyCode>,
+    pub move_right: Vec<KeyCode>,
+    pub dash_keys: Vec<KeyCode>,
+    pub pause_keys: Vec<KeyCode>,
+    pub dash_buttons: Vec<GamepadButton>,
+    pub pause_buttons: Vec<GamepadButton>,
+
+    /// Stick magnitude (0.0 to 1.0) below which gamepad movement is ignored.
+    pub stick_deadzone: f32,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            move_up: vec![KeyCode::KeyW, KeyCode::ArrowUp],
+            move_down: vec![KeyCode::KeyS, KeyCode::ArrowDown],
+            move_left: vec![KeyCode::KeyA, KeyCode::ArrowLeft],
+            move_right: vec![KeyCode::KeyD, KeyCode::ArrowRight],
+            dash_keys: vec![KeyCode::Space, KeyCode::ShiftLeft],
+            pause_keys: vec![KeyCode::Escape, KeyCode::KeyP],
+            dash_buttons: vec![GamepadButton::South, GamepadButton::RightTrigger],
+            pause_buttons: vec![GamepadButton::Start],
+            stick_deadzone: 0.15,
+        }
+    }
+}
\ No newline at end of file
@@ -4,12 +4,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Resource, Reflect, Debug, Default)]
 #[reflect(Resource)]
 pub struct Leaderboard {
-    pub entries: Vec<(String, usize, f32)>,
+    pub entries: Vec<LeaderboardEntry>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct FirebaseEntry {
+#[derive(Serialize, Deserialize, Reflect, Debug, Clone)]
+pub struct LeaderboardEntry {
     pub name: String,
     pub score: usize,
     pub time: f32,
+    /// Whether the run ended in victory. Older entries have no tag and count as losses.
+    #[serde(default)]
+    pub won: bool,
 }
\ No newline at end of file
@@ -0,0 +1,238 @@
+use bevy::prelude::*;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use super::leaderboard::LeaderboardEntry;
+use super::leaderboard_config::LeaderboardConfig;
+
+pub type FetchResult = Result<Vec<LeaderboardEntry>, LeaderboardError>;
+pub type SubmitResult = Result<(), LeaderboardError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LeaderboardError {
+    /// The request never got a response.
+    Network(String),
+    /// The backend answered with a non-success HTTP status.
+    Status(u16),
+    /// The response or file could not be decoded.
+    Parse(String),
+    /// Reading or writing local storage failed.
+    Io(String),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network(e) => write!(f, "network error: {}", e),
+            Self::Status(code) => write!(f, "HTTP status {}", code),
+            Self::Parse(e) => write!(f, "bad data: {}", e),
+            Self::Io(e) => write!(f, "storage error: {}", e),
+        }
+    }
+}
+
+impl LeaderboardError {
+    /// Short explanation for the leaderboard panel.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Network(_) => "CAN'T REACH THE LEADERBOARD".to_string(),
+            Self::Status(code) => format!("SERVER ERROR ({})", code),
+            Self::Parse(_) => "UNREADABLE LEADERBOARD DATA".to_string(),
+            Self::Io(_) => "CAN'T ACCESS LOCAL SCORES".to_string(),
+        }
+    }
+}
+
+/// Storage behind the leaderboard.
+///
+/// Calls may finish on another thread, so results are handed to `on_done`
+/// instead of being returned.
+pub trait LeaderboardBackend: Send + Sync {
+    /// Fetches the best `limit` entries, highest score first.
+    fn fetch_top(&self, limit: usize, on_done: Box<dyn FnOnce(FetchResult) + Send>);
+
+    fn submit(&self, entry: LeaderboardEntry, on_done: Box<dyn FnOnce(SubmitResult) + Send>);
+}
+
+/// The active backend. `None` when the leaderboard is disabled.
+#[derive(Resource, Default)]
+pub struct LeaderboardService {
+    pub backend: Option<Box<dyn LeaderboardBackend>>,
+}
+
+impl LeaderboardService {
+    /// Picks the backend from the URL scheme: `file://<path>` for a JSON file on disk,
+    /// `memory://` for an in-process list, anything else is treated as a Firebase REST URL.
+    pub fn from_config(config: &LeaderboardConfig) -> Self {
+        let backend = config.url.as_deref().map(|url| -> Box<dyn LeaderboardBackend> {
+            if let Some(path) = url.strip_prefix("file://") {
+                Box::new(FileBackend { path: PathBuf::from(path) })
+            } else if url.starts_with("memory://") {
+                Box::new(MemoryBackend::default())
+            } else {
+                Box::new(FirebaseBackend::new(url))
+            }
+        });
+
+        Self { backend }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.backend.is_some()
+    }
+}
+
+fn sort_and_truncate(mut entries: Vec<LeaderboardEntry>, limit: usize) -> Vec<LeaderboardEntry> {
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+    entries.truncate(limit);
+    entries
+}
+
+/// Firebase Realtime Database over its REST API.
+pub struct FirebaseBackend {
+    base_url: String,
+}
+
+impl FirebaseBackend {
+    pub fn new(url: &str) -> Self {
+        let base_url = if url.ends_with('/') { url.to_string() } else { format!("{url}/") };
+        Self { base_url }
+    }
+}
+
+impl LeaderboardBackend for FirebaseBackend {
+    fn fetch_top(&self, limit: usize, on_done: Box<dyn FnOnce(FetchResult) + Send>) {
+        let url = format!("{}leaderboard.json?orderBy=\"score\"&limitToLast={}", self.base_url, limit);
+
+        ehttp::fetch(ehttp::Request::get(url), move |result| {
+            let result = match result {
+                Err(e) => Err(LeaderboardError::Network(e)),
+                Ok(response) if response.status != 200 => Err(LeaderboardError::Status(response.status)),
+                Ok(response) => serde_json::from_slice::<serde_json::Value>(&response.bytes)
+                    .map_err(|e| LeaderboardError::Parse(e.to_string()))
+                    .map(|raw_json| {
+                        // Firebase answers `null` for an empty database, and an object keyed by push id otherwise
+                        let entries = raw_json.as_object()
+                            .map(|obj| obj.values()
+                                .filter_map(|val| serde_json::from_value(val.clone()).ok())
+                                .collect())
+                            .unwrap_or_default();
+                        sort_and_truncate(entries, limit)
+                    }),
+            };
+            on_done(result);
+        });
+    }
+
+    fn submit(&self, entry: LeaderboardEntry, on_done: Box<dyn FnOnce(SubmitResult) + Send>) {
+        let url = format!("{}leaderboard.json", self.base_url);
+        let json = match serde_json::to_vec(&entry) {
+            Ok(json) => json,
+            Err(e) => return on_done(Err(LeaderboardError::Parse(e.to_string()))),
+        };
+
+        ehttp::fetch(ehttp::Request::post(url, json), move |result| {
+            on_done(match result {
+                Err(e) => Err(LeaderboardError::Network(e)),
+                Ok(response) if response.status != 200 => Err(LeaderboardError::Status(response.status)),
+                Ok(_) => Ok(()),
+            });
+        });
+    }
+}
+
+/// A JSON array of entries in a local file, for offline builds.
+pub struct FileBackend {
+    pub path: PathBuf,
+}
+
+impl FileBackend {
+    fn read_all(&self) -> FetchResult {
+        match std::fs::read(&self.path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| LeaderboardError::Parse(e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(LeaderboardError::Io(e.to_string())),
+        }
+    }
+}
+
+impl LeaderboardBackend for FileBackend {
+    fn fetch_top(&self, limit: usize, on_done: Box<dyn FnOnce(FetchResult) + Send>) {
+        on_done(self.read_all().map(|entries| sort_and_truncate(entries, limit)));
+    }
+
+    fn submit(&self, entry: LeaderboardEntry, on_done: Box<dyn FnOnce(SubmitResult) + Send>) {
+        let result = self.read_all().and_then(|mut entries| {
+            entries.push(entry);
+            let json = serde_json::to_vec_pretty(&entries).map_err(|e| LeaderboardError::Parse(e.to_string()))?;
+            std::fs::write(&self.path, json).map_err(|e| LeaderboardError::Io(e.to_string()))
+        });
+        on_done(result);
+    }
+}
+
+/// Keeps entries in memory only. Clones share the same list, so tests can keep
+/// a handle and inspect what was submitted.
+#[derive(Clone, Default)]
+pub struct MemoryBackend {
+    pub entries: Arc<Mutex<Vec<LeaderboardEntry>>>,
+}
+
+impl LeaderboardBackend for MemoryBackend {
+    fn fetch_top(&self, limit: usize, on_done: Box<dyn FnOnce(FetchResult) + Send>) {
+        let entries = self.entries.lock().map(|list| list.clone()).unwrap_or_default();
+        on_done(Ok(sort_and_truncate(entries, limit)));
+    }
+
+    fn submit(&self, entry: LeaderboardEntry, on_done: Box<dyn FnOnce(SubmitResult) + Send>) {
+        if let Ok(mut list) = self.entries.lock() {
+            list.push(entry);
+        }
+        on_done(Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, score: usize) -> LeaderboardEntry {
+        LeaderboardEntry { name: name.to_string(), score, time: 60.0, won: false }
+    }
+
+    #[test]
+    fn memory_backend_clones_share_entries() {
+        let handle = MemoryBackend::default();
+        let backend: Box<dyn LeaderboardBackend> = Box::new(handle.clone());
+
+        for (name, score) in [("low", 100), ("high", 900), ("mid", 500)] {
+            backend.submit(entry(name, score), Box::new(|result| assert_eq!(result, Ok(()))));
+        }
+        assert_eq!(handle.entries.lock().unwrap().len(), 3);
+
+        backend.fetch_top(2, Box::new(|result| {
+            let names: Vec<String> = result.unwrap().into_iter().map(|entry| entry.name).collect();
+            assert_eq!(names, ["high", "mid"]);
+        }));
+    }
+
+    #[test]
+    fn file_backend_keeps_entries_across_reloads() {
+        let path = std::env::temp_dir().join(format!("leaderboard_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let backend = FileBackend { path: path.clone() };
+        for (name, score) in [("low", 100), ("high", 900), ("mid", 500)] {
+            backend.submit(entry(name, score), Box::new(|result| assert_eq!(result, Ok(()))));
+        }
+
+        let reloaded = FileBackend { path: path.clone() };
+        reloaded.fetch_top(2, Box::new(|result| {
+            let names: Vec<String> = result.unwrap().into_iter().map(|entry| entry.name).collect();
+            assert_eq!(names, ["high", "mid"]);
+        }));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
\ No newline at end of file
@@ -2,10 +2,12 @@ use bevy::prelude::*;
 use std::sync::mpsc::{Receiver, Sender};
 use std::sync::Mutex;
 
+use super::leaderboard_backend::FetchResult;
+
 #[derive(Resource)]
 pub struct LeaderboardChannel {
-    pub tx: Sender<Vec<(String, usize, f32)>>,
-    pub rx: Mutex<Receiver<Vec<(String, usize, f32)>>>,
+    pub tx: Sender<FetchResult>,
+    pub rx: Mutex<Receiver<FetchResult>>,
 }
 
 impl Default for LeaderboardChannel {
@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Config file read from the working directory when no CLI argument or env var is given.
+pub const LEADERBOARD_CONFIG_FILE: &str = "leaderboard_config.json";
+
+/// Where the leaderboard lives. `url` is `None` when no backend is configured,
+/// in which case the leaderboard and score submission are hidden.
+///
+/// See [LeaderboardService](super::leaderboard_backend::LeaderboardService) for the supported URL schemes.
+#[derive(Resource, Debug, Default)]
+pub struct LeaderboardConfig {
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LeaderboardConfigFile {
+    url: Option<String>,
+}
+
+impl LeaderboardConfig {
+    /// Resolves the backend URL, first match wins:
+    /// 1. `--leaderboard-url <url>` on the command line
+    /// 2. the `FIREBASE_URL` environment variable
+    /// 3. `url` in [LEADERBOARD_CONFIG_FILE]
+    /// 4. `FIREBASE_URL` baked in at build time (the only option on the web)
+    pub fn resolve() -> Self {
+        let from_args = std::env::args()
+            .skip_while(|arg| arg != "--leaderboard-url")
+            .nth(1);
+        let from_env = std::env::var("FIREBASE_URL").ok();
+        let from_file = Self::read_file(Path::new(LEADERBOARD_CONFIG_FILE));
+        let from_build = option_env!("FIREBASE_URL").map(str::to_string);
+
+        let config = Self::first_of([from_args, from_env, from_file, from_build]);
+        match &config.url {
+            Some(url) => info!("LEADERBOARD: using {}", url),
+            None => info!("LEADERBOARD: no backend configured, leaderboard disabled"),
+        }
+        config
+    }
+
+    /// The first URL that isn't blank, from sources listed in [Self::resolve]'s order.
+    fn first_of(sources: [Option<String>; 4]) -> Self {
+        let url = sources
+            .into_iter()
+            .flatten()
+            .map(|url| url.trim().to_string())
+            .find(|url| !url.is_empty());
+        Self { url }
+    }
+
+    /// `url` from a config file, or `None` if the file is missing or unreadable.
+    fn read_file(path: &Path) -> Option<String> {
+        std::fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<LeaderboardConfigFile>(&bytes).ok())
+            .and_then(|file| file.url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn command_line_wins() {
+        let config = LeaderboardConfig::first_of([url("arg://"), url("env://"), url("file://"), url("build://")]);
+        assert_eq!(config.url.as_deref(), Some("arg://"));
+    }
+
+    #[test]
+    fn env_var_beats_file_and_build() {
+        let config = LeaderboardConfig::first_of([None, url("env://"), url("file://"), url("build://")]);
+        assert_eq!(config.url.as_deref(), Some("env://"));
+    }
+
+    #[test]
+    fn file_beats_build() {
+        let config = LeaderboardConfig::first_of([None, None, url("file://"), url("build://")]);
+        assert_eq!(config.url.as_deref(), Some("file://"));
+    }
+
+    #[test]
+    fn build_is_the_last_resort() {
+        let config = LeaderboardConfig::first_of([None, url("  "), None, url(" build:// ")]);
+        assert_eq!(config.url.as_deref(), Some("build://"));
+    }
+
+    #[test]
+    fn nothing_configured_disables_the_leaderboard() {
+        assert_eq!(LeaderboardConfig::first_of([None, url(""), None, None]).url, None);
+    }
+
+    #[test]
+    fn reads_url_from_config_file() {
+        let path = std::env::temp_dir().join(format!("leaderboard_config_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{ "url": "file://scores.json" }"#).unwrap();
+        assert_eq!(LeaderboardConfig::read_file(&path).as_deref(), Some("file://scores.json"));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(LeaderboardConfig::read_file(&path), None);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(LeaderboardConfig::read_file(&path), None);
+    }
+}
\ No newline at end of file
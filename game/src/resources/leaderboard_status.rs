@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use super::leaderboard_backend::LeaderboardError;
+
+/// Progress of the last leaderboard fetch, shown in the leaderboard panel.
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub enum LeaderboardStatus {
+    #[default]
+    Idle,
+    Loading,
+    Loaded,
+    Empty,
+    Error(LeaderboardError),
+}
\ No newline at end of file
@@ -10,6 +10,20 @@ pub(super) mod session_time;
 pub(super) mod player_profile;
 pub(super) mod leaderboard;
 pub(super) mod reset_target;
-pub(super) mod firebase_config;
+pub(super) mod leaderboard_config;
 pub(super) mod leaderboard_channel;
-pub(super) mod player_settings;
\ No newline at end of file
+pub(super) mod player_settings;
+pub(super) mod game_rng;
+pub(super) mod input_bindings;
+pub(super) mod player_input;
+pub(super) mod leaderboard_backend;
+pub(super) mod score_queue;
+pub(super) mod leaderboard_status;
+pub(super) mod user_preferences;
+pub(super) mod run_stats;
+pub(super) mod run_history;
+pub(super) mod session_snapshot;
+pub(super) mod balance_config;
+pub(super) mod tile_changes;
+pub(super) mod planet_health;
+pub(super) mod flow_field;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+
+use crate::components::planet::{PlanetTiles, TileState};
+
+/// How much of the planet is in each state, kept up to date from `TileStateChanged`
+/// messages rather than by recounting the tiles.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct PlanetHealth {
+    pub healthy: usize,
+    pub wasteland: usize,
+    pub polluted: usize,
+    /// Factories standing right now.
+    pub factories: usize,
+}
+
+impl PlanetHealth {
+    pub fn from_tiles(tiles: &PlanetTiles) -> Self {
+        let mut health = Self::default();
+        for tile in &tiles.tiles {
+            *health.count_mut(tile.state) += 1;
+        }
+        health
+    }
+
+    pub fn total(&self) -> usize {
+        self.healthy + self.wasteland + self.polluted
+    }
+
+    pub fn count(&self, state: TileState) -> usize {
+        match state {
+            TileState::Healthy => self.healthy,
+            TileState::Wasteland => self.wasteland,
+            TileState::Polluted => self.polluted,
+        }
+    }
+
+    /// Share of tiles in `state`, from 0 to 1.
+    pub fn fraction(&self, state: TileState) -> f32 {
+        let total = self.total();
+        if total == 0 { 0.0 } else { self.count(state) as f32 / total as f32 }
+    }
+
+    /// Moves one tile from `from` to `to`.
+    pub fn apply(&mut self, from: TileState, to: TileState) {
+        *self.count_mut(from) -= 1;
+        *self.count_mut(to) += 1;
+    }
+
+    fn count_mut(&mut self, state: TileState) -> &mut usize {
+        match state {
+            TileState::Healthy => &mut self.healthy,
+            TileState::Wasteland => &mut self.wasteland,
+            TileState::Polluted => &mut self.polluted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_planet_has_no_share_of_anything() {
+        let health = PlanetHealth::default();
+        assert_eq!(health.total(), 0);
+        assert_eq!(health.fraction(TileState::Healthy), 0.0);
+        assert_eq!(health.fraction(TileState::Polluted), 0.0);
+    }
+
+    #[test]
+    fn tiles_move_between_states_both_ways() {
+        let mut health = PlanetHealth { wasteland: 4, ..default() };
+
+        health.apply(TileState::Wasteland, TileState::Polluted);
+        health.apply(TileState::Wasteland, TileState::Polluted);
+        health.apply(TileState::Polluted, TileState::Healthy);
+        assert_eq!(health, PlanetHealth { healthy: 1, wasteland: 2, polluted: 1, factories: 0 });
+        assert_eq!(health.fraction(TileState::Healthy), 0.25);
+
+        health.apply(TileState::Healthy, TileState::Polluted);
+        health.apply(TileState::Polluted, TileState::Wasteland);
+        assert_eq!(health, PlanetHealth { healthy: 0, wasteland: 3, polluted: 1, factories: 0 });
+        assert_eq!(health.total(), 4);
+        assert_eq!(health.fraction(TileState::Wasteland), 0.75);
+    }
+}
\ No newline at end of file
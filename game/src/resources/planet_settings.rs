@@ -1,14 +1,37 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
-#[derive(Resource, Reflect, Debug)]
+/// Shape of the tiles the planet surface is split into.
+#[derive(Reflect, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlanetTiling {
+    /// The faces of an ico-sphere.
+    #[default]
+    Triangles,
+    /// A Goldberg polyhedron: hexagons plus 12 pentagons.
+    Hexagons,
+}
+
+#[derive(Resource, Reflect, Deserialize, Debug, Clone)]
 #[reflect(Resource)]
+#[serde(default, deny_unknown_fields)]
 pub struct PlanetSettings {
+    /// Only read when the planet is built at startup; a balance reload keeps the current one.
     pub radius: f32,
+    /// Only read when the planet mesh is built at startup. At most 79, the finest
+    /// ico-sphere Bevy can build.
     pub subdivisions: u32,
+    /// Only read when the planet mesh is built at startup.
+    pub tiling: PlanetTiling,
     pub friction: f32,
     pub orb_hp_gain: f32,
     pub max_orbs: usize,
     pub orb_spawn_chance: f32,
+    /// Fraction of Healthy tiles needed to win, once every factory is destroyed.
+    pub victory_healthy_ratio: f32,
+    /// A win before this many seconds earns [PlanetSettings::time_bonus_per_sec] for each
+    /// second left.
+    pub par_time: f32,
+    pub time_bonus_per_sec: f32,
 }
 
 impl Default for PlanetSettings {
@@ -16,10 +39,14 @@ impl Default for PlanetSettings {
         Self {
             radius: 150.0,
             subdivisions: 16,
+            tiling: PlanetTiling::Triangles,
             friction: 0.985,
             orb_hp_gain: 25.0,
             max_orbs: 10,
             orb_spawn_chance: 0.002,
+            victory_healthy_ratio: 0.9,
+            par_time: 600.0,
+            time_bonus_per_sec: 20.0,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+/// The device the player used last. On-screen controls are only shown for `Pointer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum InputDevice {
+    /// Mouse or touch driving the virtual joystick.
+    #[default]
+    Pointer,
+    Keyboard,
+    Gamepad,
+}
+
+/// Actions read from keyboard and gamepad this frame.
+#[derive(Resource, Default, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct PlayerInput {
+    pub device: InputDevice,
+    pub move_dir: Vec2,
+    pub dash: bool,
+    pub pause: bool,
+}
\ No newline at end of file
@@ -1,9 +1,13 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
-#[derive(Resource, Reflect, Debug)]
+#[derive(Resource, Reflect, Deserialize, Debug, Clone)]
 #[reflect(Resource)]
+#[serde(default, deny_unknown_fields)]
 pub struct PlayerSettings {
     pub player_speed: f32,
+    /// Follows the player's health at runtime, so it isn't read from balance files.
+    #[serde(skip)]
     pub player_radius: f32,
     pub camera_height: f32,
     pub acceleration: f32,
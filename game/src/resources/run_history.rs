@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::run_stats::RunStats;
+
+/// File in the data directory holding finished runs.
+pub const RUN_HISTORY_FILE: &str = "run_history.json";
+
+/// Oldest runs are dropped past this many. Bests are tracked separately so they survive.
+const MAX_RUNS_KEPT: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RunRecord {
+    pub score: usize,
+    /// Seconds survived.
+    pub duration: f32,
+    pub seed: u64,
+    /// Unix timestamp (seconds) of the game over.
+    pub date: u64,
+    #[serde(default)]
+    pub stats: RunStats,
+    #[serde(default)]
+    pub won: bool,
+}
+
+impl RunRecord {
+    /// `YYYY-MM-DD` in UTC.
+    pub fn date_label(&self) -> String {
+        let (year, month, day) = civil_from_days((self.date / 86_400) as i64);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// Best values across every recorded run.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct PersonalBests {
+    pub score: usize,
+    pub duration: f32,
+    pub tiles_restored: u32,
+    pub factories_destroyed: u32,
+}
+
+/// Every finished run on this machine, newest last.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct RunHistory {
+    pub runs: Vec<RunRecord>,
+    pub bests: PersonalBests,
+    /// Whether the newest run beat the previous best score. Not saved.
+    #[serde(skip)]
+    pub latest_is_best: bool,
+}
+
+impl RunHistory {
+    /// Appends a run and updates the bests.
+    pub fn record(&mut self, run: RunRecord) {
+        self.latest_is_best = run.score > self.bests.score;
+
+        self.bests.score = self.bests.score.max(run.score);
+        self.bests.duration = self.bests.duration.max(run.duration);
+        self.bests.tiles_restored = self.bests.tiles_restored.max(run.stats.tiles_restored);
+        self.bests.factories_destroyed = self.bests.factories_destroyed.max(run.stats.factories_destroyed);
+
+        self.runs.push(run);
+        let overflow = self.runs.len().saturating_sub(MAX_RUNS_KEPT);
+        self.runs.drain(..overflow);
+    }
+
+    /// The most recent runs, newest first.
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = &RunRecord> {
+        self.runs.iter().rev().take(count)
+    }
+}
+
+/// Days since 1970-01-01 to a (year, month, day) date, after Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(score: usize, duration: f32) -> RunRecord {
+        RunRecord { score, duration, seed: 0, date: 0, stats: RunStats::default(), won: false }
+    }
+
+    #[test]
+    fn civil_dates_across_leap_years() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(10_956), (1999, 12, 31));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(civil_from_days(19_781), (2024, 2, 28));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(19_783), (2024, 3, 1));
+        assert_eq!(civil_from_days(19_417), (2023, 3, 1));
+    }
+
+    #[test]
+    fn date_label_is_utc_day() {
+        let record = RunRecord { date: 19_782 * 86_400 + 86_399, ..run(0, 0.0) };
+        assert_eq!(record.date_label(), "2024-02-29");
+    }
+
+    #[test]
+    fn only_a_strictly_higher_score_is_a_personal_best() {
+        let mut history = RunHistory::default();
+
+        history.record(run(500, 30.0));
+        assert!(history.latest_is_best);
+
+        history.record(run(200, 90.0));
+        assert!(!history.latest_is_best);
+
+        history.record(run(500, 10.0));
+        assert!(!history.latest_is_best);
+
+        history.record(run(800, 10.0));
+        assert!(history.latest_is_best);
+        assert_eq!(history.bests.score, 800);
+        assert_eq!(history.bests.duration, 90.0);
+        assert_eq!(history.runs.len(), 4);
+    }
+
+    #[test]
+    fn keeps_only_the_newest_runs() {
+        let mut history = RunHistory::default();
+        for score in 0..MAX_RUNS_KEPT + 3 {
+            history.record(run(score, 0.0));
+        }
+        assert_eq!(history.runs.len(), MAX_RUNS_KEPT);
+        assert_eq!(history.runs[0].score, 3);
+        assert_eq!(history.bests.score, MAX_RUNS_KEPT + 2);
+    }
+}
\ No newline at end of file
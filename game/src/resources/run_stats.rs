@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Counters for the current run, kept alongside [Score](super::score::Score) and
+/// cleared by the world reset.
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct RunStats {
+    pub tiles_restored: u32,
+    pub machines_destroyed: u32,
+    pub factories_destroyed: u32,
+    pub orbs_collected: u32,
+}
\ No newline at end of file
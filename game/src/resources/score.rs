@@ -4,6 +4,8 @@ use bevy::prelude::*;
 #[reflect(Resource)]
 pub struct Score {
     pub current: usize,
+    /// Part of `current` awarded for winning early.
+    pub time_bonus: usize,
 }
 
 #[derive(Message)]
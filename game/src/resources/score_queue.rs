@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::Mutex;
+
+use super::leaderboard::LeaderboardEntry;
+use super::leaderboard_backend::SubmitResult;
+
+/// File in the data directory holding the queue between launches.
+pub const SCORE_QUEUE_FILE: &str = "score_queue.json";
+
+/// How many delivered submissions are kept around so the menu can show them as sent.
+const MAX_SENT_KEPT: usize = 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum SubmissionStatus {
+    Pending,
+    Sent,
+    Failed,
+}
+
+impl SubmissionStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pending => "PENDING",
+            Self::Sent => "SENT",
+            Self::Failed => "FAILED",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct QueuedSubmission {
+    pub id: u64,
+    pub entry: LeaderboardEntry,
+    pub status: SubmissionStatus,
+    pub attempts: u32,
+    /// `Time::elapsed_secs_f64` before which no retry is made. Not saved, so a
+    /// fresh launch retries straight away.
+    #[serde(skip)]
+    pub retry_at: f64,
+    #[serde(skip)]
+    pub in_flight: bool,
+}
+
+/// Leaderboard submissions that have not been confirmed by the backend yet.
+///
+/// Entries are saved to disk on every change, retried with exponential
+/// backoff, and only counted as accepted once the backend says so.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct ScoreQueue {
+    pub items: Vec<QueuedSubmission>,
+    next_id: u64,
+}
+
+impl ScoreQueue {
+    pub fn push(&mut self, entry: LeaderboardEntry) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push(QueuedSubmission {
+            id,
+            entry,
+            status: SubmissionStatus::Pending,
+            attempts: 0,
+            retry_at: 0.0,
+            in_flight: false,
+        });
+        id
+    }
+
+    pub fn get(&self, id: u64) -> Option<&QueuedSubmission> {
+        self.items.iter().find(|item| item.id == id)
+    }
+
+    /// Makes every unsent entry due immediately.
+    pub fn retry_now(&mut self) {
+        for item in self.items.iter_mut().filter(|item| item.status != SubmissionStatus::Sent) {
+            item.retry_at = 0.0;
+        }
+    }
+
+    /// Delay before the next attempt: 2, 4, 8... seconds, capped at five minutes.
+    pub fn backoff_secs(attempts: u32) -> f64 {
+        (2.0f64.powi(attempts.min(16) as i32)).min(300.0)
+    }
+
+    /// Drops all but the most recent delivered entries.
+    pub fn prune_sent(&mut self) {
+        let sent = self.items.iter().filter(|item| item.status == SubmissionStatus::Sent).count();
+        let mut to_drop = sent.saturating_sub(MAX_SENT_KEPT);
+        self.items.retain(|item| {
+            if to_drop > 0 && item.status == SubmissionStatus::Sent {
+                to_drop -= 1;
+                return false;
+            }
+            true
+        });
+    }
+}
+
+/// Carries submission results back from the backend callbacks, keyed by [QueuedSubmission::id].
+#[derive(Resource)]
+pub struct ScoreQueueChannel {
+    pub tx: Sender<(u64, SubmitResult)>,
+    pub rx: Mutex<Receiver<(u64, SubmitResult)>>,
+}
+
+impl Default for ScoreQueueChannel {
+    fn default() -> Self {
+        let (tx, rx) = std::sync::mpsc::channel();
+        Self { tx, rx: Mutex::new(rx) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: usize) -> LeaderboardEntry {
+        LeaderboardEntry { name: "TEST".to_string(), score, time: 60.0, won: false }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_five_minutes() {
+        let delays: Vec<f64> = (1..=10).map(ScoreQueue::backoff_secs).collect();
+        assert_eq!(delays, [2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 300.0, 300.0]);
+        assert_eq!(ScoreQueue::backoff_secs(u32::MAX), 300.0);
+    }
+
+    #[test]
+    fn prune_keeps_newest_sent_and_all_unsent() {
+        let mut queue = ScoreQueue::default();
+        for score in 0..8 {
+            let id = queue.push(entry(score));
+            queue.items.iter_mut().find(|item| item.id == id).unwrap().status = SubmissionStatus::Sent;
+        }
+        let pending = queue.push(entry(100));
+        let failed = queue.push(entry(200));
+        queue.items.iter_mut().find(|item| item.id == failed).unwrap().status = SubmissionStatus::Failed;
+
+        queue.prune_sent();
+
+        let sent: Vec<u64> = queue.items.iter()
+            .filter(|item| item.status == SubmissionStatus::Sent)
+            .map(|item| item.id)
+            .collect();
+        assert_eq!(sent, [3, 4, 5, 6, 7]);
+        assert!(queue.get(pending).is_some());
+        assert!(queue.get(failed).is_some());
+    }
+}
\ No newline at end of file
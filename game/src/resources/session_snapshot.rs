@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::machine::{BrainState, MachineKind};
+use crate::components::planet::TileState;
+use super::dash_state::DashState;
+use super::run_stats::RunStats;
+
+/// File in the data directory holding the paused run.
+pub const SESSION_SNAPSHOT_FILE: &str = "session.json";
+
+/// Snapshots with any other version are ignored.
+pub const SNAPSHOT_VERSION: u32 = 6;
+
+/// Everything needed to rebuild a run in progress.
+///
+/// Vectors are stored as plain arrays and tile states as one character per tile
+/// (`W`, `H`, `P`) to keep the file small.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionSnapshot {
+    pub version: u32,
+    pub seed: u64,
+    /// How far into the seed's stream [GameRng](super::game_rng::GameRng) had drawn.
+    pub rng_word_pos: u128,
+    pub score: usize,
+    pub elapsed: f32,
+    pub stats: RunStats,
+    pub difficulty_scale: f32,
+    pub factory_spawn_elapsed: f32,
+    /// One entry per `PlanetTiles` tile.
+    pub tiles: String,
+    pub pivot_rotation: [f32; 4],
+    pub player: PlayerSnapshot,
+    pub dash: DashState,
+    pub factories: Vec<FactorySnapshot>,
+    pub machines: Vec<MachineSnapshot>,
+    pub orbs: Vec<[f32; 3]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlayerSnapshot {
+    pub velocity: [f32; 3],
+    pub hp: f32,
+    pub invincibility_timer: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FactorySnapshot {
+    pub position: [f32; 3],
+    pub spawn_interval: f32,
+    pub spawn_elapsed: f32,
+    pub hp: f32,
+    /// Seconds left before the factory starts regenerating again.
+    pub regen_cooldown: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MachineSnapshot {
+    pub position: [f32; 3],
+    pub velocity: [f32; 3],
+    pub kind: MachineKind,
+    pub stun: f32,
+    pub cooldown: f32,
+    pub state: BrainState,
+    pub home: [f32; 3],
+    pub waypoint: [f32; 3],
+    /// Seconds the machine had spent in `state`.
+    pub state_timer: f32,
+}
+
+/// The snapshot on disk, if any, and whether the next `GameState::Playing` should resume it.
+#[derive(Resource, Default)]
+pub struct SavedSession {
+    pub snapshot: Option<SessionSnapshot>,
+    pub resume: bool,
+}
+
+pub fn encode_tiles(states: impl Iterator<Item = TileState>) -> String {
+    states.map(|state| match state {
+        TileState::Wasteland => 'W',
+        TileState::Healthy => 'H',
+        TileState::Polluted => 'P',
+    }).collect()
+}
+
+/// Inverse of [encode_tiles]. `None` if the string holds anything else.
+pub fn decode_tiles(tiles: &str) -> Option<Vec<TileState>> {
+    tiles.chars().map(|c| match c {
+        'W' => Some(TileState::Wasteland),
+        'H' => Some(TileState::Healthy),
+        'P' => Some(TileState::Polluted),
+        _ => None,
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiles_round_trip() {
+        let states = [TileState::Wasteland, TileState::Healthy, TileState::Polluted, TileState::Polluted, TileState::Wasteland];
+        let encoded = encode_tiles(states.into_iter());
+        assert_eq!(encoded, "WHPPW");
+        assert_eq!(decode_tiles(&encoded), Some(states.to_vec()));
+        assert_eq!(decode_tiles(""), Some(vec![]));
+    }
+
+    #[test]
+    fn unknown_tile_characters_are_rejected() {
+        assert_eq!(decode_tiles("WHX"), None);
+        assert_eq!(decode_tiles("wh"), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+use crate::components::planet::TileState;
+
+/// Why a tile changed state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileChangeCause {
+    /// The player rolled over it.
+    Player,
+    /// Stained by a factory landing nearby.
+    FactorySpawn,
+    /// Pollution spreading from an active patch.
+    Spread,
+    /// Left by a machine: a polluter's trail, or a kamikaze blast.
+    Machine,
+    /// Cleaned by the shockwave of a destroyed factory.
+    Shockwave,
+    /// Wiped by the world reset.
+    Reset,
+    /// Loaded from a saved session.
+    Restore,
+}
+
+/// Sent by [TileWriter](crate::plugins::game::TileWriter) for every tile whose state
+/// actually changed.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct TileStateChanged {
+    pub tile: usize,
+    pub from: TileState,
+    pub to: TileState,
+    pub cause: TileChangeCause,
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Audio and graphics options, saved with the player profile.
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct UserPreferences {
+    /// Linear volume applied to all audio (0.0 to 1.0).
+    pub master_volume: f32,
+    pub vsync: bool,
+    pub shadows: bool,
+}
+
+impl Default for UserPreferences {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            vsync: true,
+            shadows: true,
+        }
+    }
+}
\ No newline at end of file
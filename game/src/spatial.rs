@@ -0,0 +1,195 @@
+use bevy::prelude::*;
+
+/// Bucket grid over points on the unit sphere, for "everything near here" queries.
+///
+/// Points are projected onto the six faces of the enclosing cube and sorted into an
+/// `resolution x resolution` grid per face, so a query only visits the cells under
+/// the search ball instead of every point.
+#[derive(Debug, Default, Clone)]
+pub struct SphereIndex {
+    points: Vec<Vec3>,
+    resolution: usize,
+    /// `6 * resolution * resolution` cells, face-major. Each holds indices into `points`.
+    cells: Vec<Vec<u32>>,
+}
+
+/// Smallest value the dominant coordinate of a unit vector can have.
+const MIN_MAJOR: f32 = 0.57;
+
+impl SphereIndex {
+    /// Indexes `points`, which are expected to lie on (or very near) the unit sphere.
+    /// Query results are indices into this slice.
+    pub fn build(points: &[Vec3]) -> Self {
+        // About eight points per cell
+        let resolution = ((points.len() as f32 / 48.0).sqrt().ceil() as usize).max(1);
+        let mut cells = vec![Vec::new(); 6 * resolution * resolution];
+
+        for (idx, point) in points.iter().enumerate() {
+            let (face, u, v) = Self::project(*point);
+            let cell = Self::cell_index(resolution, face, Self::to_cell(resolution, u), Self::to_cell(resolution, v));
+            cells[cell].push(idx as u32);
+        }
+
+        Self { points: points.to_vec(), resolution, cells }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Indices of all points within `radius` of `center`, in ascending order.
+    ///
+    /// `center` doesn't have to be on the sphere; it is simply the middle of the search ball.
+    pub fn within(&self, center: Vec3, radius: f32) -> Vec<usize> {
+        let mut found = Vec::new();
+        if self.is_empty() { return found; }
+
+        let radius_sq = radius * radius;
+        let min = center - Vec3::splat(radius);
+        let max = center + Vec3::splat(radius);
+
+        for face in 0..6 {
+            let axis = face / 2;
+            let sign = if face % 2 == 0 { 1.0 } else { -1.0 };
+            let (u_axis, v_axis) = ((axis + 1) % 3, (axis + 2) % 3);
+
+            // Range of the face's major coordinate (made positive) that the search box covers
+            let (lo, hi) = if sign > 0.0 { (min[axis], max[axis]) } else { (-max[axis], -min[axis]) };
+            let (lo, hi) = (lo.max(MIN_MAJOR), hi.min(1.0));
+            if lo > hi { continue; }
+
+            // u = coordinate / major is monotonic in both, so the corners bound it
+            let bounds = |axis: usize| {
+                let (a, b) = (min[axis] * sign, max[axis] * sign);
+                let (a, b) = (a.min(b), a.max(b));
+                let corners = [a / lo, a / hi, b / lo, b / hi];
+                let low = corners.iter().copied().fold(f32::INFINITY, f32::min);
+                let high = corners.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+                (Self::to_cell(self.resolution, low), Self::to_cell(self.resolution, high))
+            };
+            let (u0, u1) = bounds(u_axis);
+            let (v0, v1) = bounds(v_axis);
+
+            for u in u0..=u1 {
+                for v in v0..=v1 {
+                    for &idx in &self.cells[Self::cell_index(self.resolution, face, u, v)] {
+                        if self.points[idx as usize].distance_squared(center) < radius_sq {
+                            found.push(idx as usize);
+                        }
+                    }
+                }
+            }
+        }
+
+        found.sort_unstable();
+        found
+    }
+
+    /// Index of the point closest to `point`, which should be on (or near) the unit sphere.
+    pub fn nearest(&self, point: Vec3) -> Option<usize> {
+        if self.is_empty() { return None; }
+
+        // Start at about the spacing between points and widen until something turns up
+        let mut radius = 4.0 / (self.points.len() as f32).sqrt();
+        loop {
+            let found = self.within(point, radius);
+            if let Some(idx) = found.into_iter().min_by(|&a, &b| {
+                self.points[a].distance_squared(point).total_cmp(&self.points[b].distance_squared(point))
+            }) {
+                return Some(idx);
+            }
+            if radius > 4.0 { return None; }
+            radius *= 2.0;
+        }
+    }
+
+    /// Face (`2 * axis`, plus one for the negative side) and face coordinates in `-1..=1`.
+    fn project(point: Vec3) -> (usize, f32, f32) {
+        let abs = point.abs();
+        let axis = if abs.x >= abs.y && abs.x >= abs.z { 0 } else if abs.y >= abs.z { 1 } else { 2 };
+        let major = point[axis];
+        let face = axis * 2 + usize::from(major < 0.0);
+        let scale = 1.0 / major.abs().max(f32::EPSILON);
+        let sign = major.signum();
+        (face, point[(axis + 1) % 3] * sign * scale, point[(axis + 2) % 3] * sign * scale)
+    }
+
+    fn to_cell(resolution: usize, coord: f32) -> usize {
+        (((coord.clamp(-1.0, 1.0) + 1.0) * 0.5 * resolution as f32) as usize).min(resolution - 1)
+    }
+
+    fn cell_index(resolution: usize, face: usize, u: usize, v: usize) -> usize {
+        (face * resolution + u) * resolution + v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    /// Random points on the sphere plus the axes and cube corners, where faces meet.
+    fn points(rng: &mut StdRng) -> Vec<Vec3> {
+        let mut points: Vec<Vec3> = (0..2000).map(|_| random_direction(rng)).collect();
+        for x in [-1.0, 0.0, 1.0] {
+            for y in [-1.0, 0.0, 1.0] {
+                for z in [-1.0, 0.0, 1.0] {
+                    if let Some(dir) = Vec3::new(x, y, z).try_normalize() {
+                        points.push(dir);
+                    }
+                }
+            }
+        }
+        points
+    }
+
+    fn random_direction(rng: &mut StdRng) -> Vec3 {
+        loop {
+            let v = Vec3::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0));
+            if let Some(dir) = v.try_normalize() && v.length() <= 1.0 {
+                return dir;
+            }
+        }
+    }
+
+    fn brute_within(points: &[Vec3], center: Vec3, radius: f32) -> Vec<usize> {
+        (0..points.len()).filter(|&idx| points[idx].distance_squared(center) < radius * radius).collect()
+    }
+
+    #[test]
+    fn within_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let points = points(&mut rng);
+        let index = SphereIndex::build(&points);
+
+        for _ in 0..500 {
+            let dir = random_direction(&mut rng);
+            let center = dir * rng.random_range(0.5..1.5);
+            let radius = rng.random_range(0.01..0.8);
+            assert_eq!(index.within(center, radius), brute_within(&points, center, radius), "center {center} radius {radius}");
+        }
+        for &point in &points[2000..] {
+            for scale in [0.8, 1.0, 1.2] {
+                assert_eq!(index.within(point * scale, 0.3), brute_within(&points, point * scale, 0.3));
+            }
+        }
+        assert_eq!(index.within(Vec3::ZERO, 1.01).len(), points.len());
+    }
+
+    #[test]
+    fn nearest_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let points = points(&mut rng);
+        let index = SphereIndex::build(&points);
+
+        for _ in 0..500 {
+            let query = random_direction(&mut rng) * rng.random_range(0.8..1.2);
+            let found = index.nearest(query).unwrap();
+            let best = points.iter().map(|point| point.distance_squared(query)).fold(f32::INFINITY, f32::min);
+            assert_eq!(points[found].distance_squared(query), best, "query {query}");
+        }
+        assert_eq!(SphereIndex::build(&[]).nearest(Vec3::X), None);
+    }
+}
\ No newline at end of file
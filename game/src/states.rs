@@ -7,4 +7,15 @@ pub enum GameState {
     Resetting,
     GameOver,
     Playing,
+    /// The planet was restored; the run is over and won.
+    Victory,
+}
+
+/// Whether a run in `GameState::Playing` is advancing. Gameplay systems run only while `Running`.
+#[derive(SubStates, Debug, Clone, PartialEq, Eq, Hash, Default, Reflect)]
+#[source(GameState = GameState::Playing)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
 }
\ No newline at end of file
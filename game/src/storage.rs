@@ -0,0 +1,42 @@
+use std::path::{Path, PathBuf};
+
+use bevy::log::warn;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+const APP_DIR: &str = "GreenSphere";
+
+/// Path of `name` in the per-user data directory (queues, history, snapshots).
+/// Falls back to the working directory where the platform has none.
+pub fn data_path(name: &str) -> PathBuf {
+    dirs::data_dir().map(|dir| dir.join(APP_DIR)).unwrap_or_default().join(name)
+}
+
+/// Path of `name` in the per-user config directory (settings and profile).
+pub fn config_path(name: &str) -> PathBuf {
+    dirs::config_dir().map(|dir| dir.join(APP_DIR)).unwrap_or_default().join(name)
+}
+
+/// Reads a JSON file. Missing or unreadable files yield `None`.
+pub fn load_json<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let bytes = std::fs::read(path).ok()?;
+    match serde_json::from_slice(&bytes) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!("STORAGE: ignoring corrupt {}: {}", path.display(), e);
+            None
+        }
+    }
+}
+
+/// Writes `value` as JSON, creating parent directories and logging instead of failing.
+pub fn save_json<T: Serialize>(path: &Path, value: &T) {
+    let result = path.parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| serde_json::to_vec_pretty(value).map_err(std::io::Error::other))
+        .and_then(|json| std::fs::write(path, json));
+
+    if let Err(e) = result {
+        warn!("STORAGE: failed to save {}: {}", path.display(), e);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+//! Runs the headless simulation with a pinned seed and checks that it plays out
+//! exactly the same way every time.
+
+use bevy::prelude::*;
+use bevy::ecs::schedule::ExecutorKind;
+
+use game::{GameRng, GameSeed, HeadlessPlugin, PlanetHealth, Score};
+
+const SEED: u64 = 42;
+
+/// Ten simulated seconds: enough for machines to spawn and pollution to spread.
+const FRAMES: usize = 600;
+
+fn headless_app(seed: u64) -> App {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugin);
+    app.world_mut().resource_mut::<GameSeed>().pinned = Some(seed);
+    app
+}
+
+fn simulate(seed: u64) -> (usize, PlanetHealth) {
+    let mut app = headless_app(seed);
+    for _ in 0..FRAMES {
+        app.update();
+    }
+
+    let world = app.world();
+    assert_eq!(world.resource::<GameSeed>().current, seed);
+
+    let health = *world.resource::<PlanetHealth>();
+    assert!(health.total() > 0, "the planet was never built");
+    assert!(health.polluted > 0, "no pollution spread in {} frames", FRAMES);
+    (world.resource::<Score>().current, health)
+}
+
+/// How far the shared RNG has read after each frame of a run on `executor`.
+fn draws(seed: u64, executor: ExecutorKind) -> Vec<u128> {
+    let mut app = headless_app(seed);
+    app.edit_schedule(Update, |schedule| {
+        schedule.set_executor_kind(executor);
+    });
+
+    (0..FRAMES).map(|_| {
+        app.update();
+        app.world().resource::<GameRng>().word_pos()
+    }).collect()
+}
+
+#[test]
+fn same_seed_gives_same_run() {
+    assert_eq!(simulate(SEED), simulate(SEED));
+}
+
+#[test]
+fn same_seed_draws_the_same_numbers() {
+    let first = draws(SEED, ExecutorKind::MultiThreaded);
+    assert!(first.last().is_some_and(|&pos| pos > 0));
+    assert_eq!(draws(SEED, ExecutorKind::MultiThreaded), first);
+    assert_eq!(draws(SEED, ExecutorKind::SingleThreaded), first);
+}
\ No newline at end of file